@@ -0,0 +1,254 @@
+//! Derive macro for the [`dst`](https://docs.rs/dst) crate, implementing
+//! `Dst` and `DstTail` for a `#[repr(C)]` struct whose last field is a slice.
+//! Enable the `derive` flag of `dst` instead of depending on this directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    parenthesized,
+    parse_macro_input,
+    parse_quote,
+    spanned::Spanned,
+    token,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Index,
+    Member,
+    Type,
+};
+
+/// Implements `Dst` and `DstTail` for a `#[repr(C)]` struct whose last field
+/// is a slice, so it can be stored directly as the tail of a `TailVec` or
+/// allocated with `Dst::new_boxed`. The other collections of `dst` only take
+/// slice tails. Adding `#[dst(clone)]` also implements `CloneTail`, which
+/// requires all fields to be [`Clone`]. The `packed` and `align`
+/// representations are not supported.
+///
+/// ```rust,ignore
+/// #[derive(Dst)]
+/// #[dst(clone)]
+/// #[repr(C)]
+/// struct Row {
+///     id: u32,
+///     name: &'static str,
+///     cells: [f64],
+/// }
+/// ```
+#[proc_macro_derive(Dst, attributes(dst))]
+pub fn derive_dst(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options read from the attributes of the struct.
+struct Options {
+    repr_c: bool,
+    clone: bool,
+}
+
+impl Options {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let mut options = Options {
+            repr_c: false,
+            clone: false,
+        };
+        for attr in &input.attrs {
+            if attr.path().is_ident("repr") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("C") {
+                        options.repr_c = true;
+                    } else if meta.path.is_ident("packed") || meta.path.is_ident("align") {
+                        return Err(meta.error("`#[derive(Dst)]` does not support `packed` or `align` representations"));
+                    } else if meta.input.peek(token::Paren) {
+                        let content;
+                        parenthesized!(content in meta.input);
+                        content.parse::<TokenStream2>()?;
+                    }
+                    Ok(())
+                })?;
+            } else if attr.path().is_ident("dst") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("clone") {
+                        options.clone = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported dst attribute"))
+                    }
+                })?;
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let options = Options::parse(&input)?;
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new(input.span(), "`#[derive(Dst)]` only supports structs")),
+    };
+    if !options.repr_c {
+        return Err(Error::new(input.ident.span(), "`#[derive(Dst)]` requires `#[repr(C)]`"));
+    }
+    let members: Vec<(Member, &Type)> = match fields {
+        Fields::Named(fields) => fields.named
+            .iter()
+            .map(|field| (Member::Named(field.ident.clone().unwrap()), &field.ty))
+            .collect(),
+        Fields::Unnamed(fields) => fields.unnamed
+            .iter()
+            .enumerate()
+            .map(|(ix, field)| (Member::Unnamed(Index::from(ix)), &field.ty))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let ((tail, tail_type), sized) = members
+        .split_last()
+        .ok_or_else(|| Error::new(input.span(), "`#[derive(Dst)]` requires a trailing slice field"))?;
+    let cell = match tail_type {
+        Type::Slice(slice) => &slice.elem,
+        other => return Err(Error::new(other.span(), "the last field must be a slice")),
+    };
+    let sized_members: Vec<&Member> = sized.iter().map(|(member, _)| member).collect();
+    let sized_types: Vec<&Type> = sized.iter().map(|(_, ty)| *ty).collect();
+    let bindings: Vec<_> = (0..sized.len()).map(|ix| format_ident!("field_{}", ix)).collect();
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut expanded = quote! {
+        unsafe impl #impl_generics ::dst::DstTail for #name #type_generics #where_clause {
+            type Metadata = usize;
+
+            #[inline(always)]
+            fn metadata(&self) -> usize {
+                self.#tail.len()
+            }
+
+            #[inline(always)]
+            fn handle_ptr<__V>(ptr: *mut u8, metadata: usize) -> *mut ::dst::Handle<__V, Self> {
+                ::core::ptr::slice_from_raw_parts_mut(ptr as *mut #cell, metadata)
+                    as *mut ::dst::Handle<__V, Self>
+            }
+        }
+
+        unsafe impl #impl_generics ::dst::Dst for #name #type_generics #where_clause {
+            type Fields = (#(#sized_types,)*);
+            type Cell = #cell;
+
+            fn value_layout(length: usize) -> ::std::alloc::Layout {
+                let layout = ::std::alloc::Layout::new::<()>();
+                #(
+                    let (layout, _) = layout
+                        .extend(::std::alloc::Layout::new::<#sized_types>())
+                        .expect("Overflow");
+                )*
+                let (layout, _) = layout
+                    .extend(::std::alloc::Layout::array::<#cell>(length).expect("Overflow"))
+                    .expect("Overflow");
+                layout.pad_to_align()
+            }
+
+            #[inline(always)]
+            fn value_ptr(ptr: *mut u8, length: usize) -> *mut Self {
+                ::core::ptr::slice_from_raw_parts_mut(ptr as *mut #cell, length) as *mut Self
+            }
+
+            unsafe fn init(
+                ptr: *mut Self,
+                length: usize,
+                fields: Self::Fields,
+                cells: &mut impl ::core::iter::Iterator<Item=Self::Cell>,
+            ) {
+                let (#(#bindings,)*) = fields;
+                #(
+                    ::core::ptr::write(::core::ptr::addr_of_mut!((*ptr).#sized_members), #bindings);
+                )*
+                let tail = ::core::ptr::addr_of_mut!((*ptr).#tail) as *mut #cell;
+                for offset in 0..length {
+                    match cells.next() {
+                        ::core::option::Option::Some(cell) => ::core::ptr::write(tail.add(offset), cell),
+                        ::core::option::Option::None => panic!("Not enough values to populate handle"),
+                    }
+                }
+            }
+        }
+    };
+
+    if options.clone {
+        let mut generics = input.generics.clone();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for ty in sized_types.iter().copied().chain(Some(&**cell)) {
+            predicates.push(parse_quote!(#ty: ::core::clone::Clone));
+        }
+        let (_, _, where_clause) = generics.split_for_impl();
+        expanded.extend(quote! {
+            unsafe impl #impl_generics ::dst::CloneTail for #name #type_generics #where_clause {
+                unsafe fn clone_to(&self, tail: *mut Self) {
+                    #(
+                        ::core::ptr::write(
+                            ::core::ptr::addr_of_mut!((*tail).#sized_members),
+                            ::core::clone::Clone::clone(&self.#sized_members),
+                        );
+                    )*
+                    let cells = ::core::ptr::addr_of_mut!((*tail).#tail) as *mut #cell;
+                    for (offset, cell) in self.#tail.iter().enumerate() {
+                        ::core::ptr::write(cells.add(offset), ::core::clone::Clone::clone(cell));
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(expanded)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn repr() {
+        assert!(expand(parse_quote! {
+            #[repr(C)]
+            struct Row { id: u8, cells: [u64] }
+        }).is_ok());
+        assert_eq!(error(parse_quote! {
+            struct Row { id: u8, cells: [u64] }
+        }), "`#[derive(Dst)]` requires `#[repr(C)]`");
+    }
+
+    #[test]
+    fn packed() {
+        let message = "`#[derive(Dst)]` does not support `packed` or `align` representations";
+        assert_eq!(error(parse_quote! {
+            #[repr(C, packed)]
+            struct Row { id: u8, x: u64, cells: [u8] }
+        }), message);
+        assert_eq!(error(parse_quote! {
+            #[repr(C)]
+            #[repr(packed(2))]
+            struct Row { id: u8, x: u64, cells: [u8] }
+        }), message);
+    }
+
+    #[test]
+    fn align() {
+        assert_eq!(error(parse_quote! {
+            #[repr(C, align(64))]
+            struct Row { id: u8, cells: [u8] }
+        }), "`#[derive(Dst)]` does not support `packed` or `align` representations");
+    }
+}
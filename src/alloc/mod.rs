@@ -1,11 +1,209 @@
 use std::{
+    alloc::{
+        alloc,
+        dealloc,
+        handle_alloc_error,
+        Layout,
+        realloc,
+    },
+    mem::MaybeUninit,
     num::NonZeroUsize,
     ptr::NonNull,
 };
+use crate::{
+    Handle,
+    util::{
+        can_try_alloc,
+        new_capacity_at_least_double,
+    },
+};
 
 type PtrCapPair = (NonNull<u8>, NonZeroUsize);
 pub type Alloc = Option<PtrCapPair>;
 
+/// Returns the never-allocated storage of zero-sized items, which is a
+/// dangling pointer with an unbounded capacity.
+#[inline(always)]
+pub fn zero_sized<T, S>(slice: usize) -> PtrCapPair {
+    dangling(Handle::<T, [S]>::alignment_slice(slice))
+}
+
+#[inline(always)]
+fn dangling(align: usize) -> PtrCapPair {
+    unsafe { (
+        NonNull::new_unchecked(align as *mut u8),
+        NonZeroUsize::new_unchecked(usize::MAX),
+    ) }
+}
+
+/// Returns the storage of a new collection of items of the layout, which
+/// is only allocated when the items are not zero-sized.
+#[inline(always)]
+pub fn empty_alloc(item: Layout) -> Alloc {
+    if item.size() == 0 {
+        Some(dangling(item.align()))
+    } else {
+        None
+    }
+}
+
+/// Returns the number of items the storage has space for.
+#[inline(always)]
+pub fn alloc_capacity(storage: &Alloc) -> usize {
+    if let Some((_, value)) = storage {
+        value.get()
+    } else {
+        0
+    }
+}
+
+/// Returns the layout of the number of items of the layout, whose size must
+/// be a multiple of its alignment.
+///
+/// # Panics
+///
+/// Panics if the size overflows.
+pub fn array_layout(item: Layout, count: NonZeroUsize) -> Layout {
+    let size = item.size()
+        .checked_mul(count.get())
+        .expect("Overflow");
+    if !can_try_alloc(size) {
+        panic!("Overflow");
+    }
+    Layout::from_size_align(size, item.align()).expect("Bad Layout")
+}
+
+/// Will insure the storage has enough space for the specified number of
+/// additional items of the layout, growing according to an internal
+/// criteria.
+pub fn reserve_alloc(storage: &mut Alloc, item: Layout, length: usize, additional: usize) {
+    let capacity = alloc_capacity(storage);
+    if capacity - length >= additional {
+        return;
+    }
+    grow_alloc(storage, item, new_capacity_at_least_double(if additional <= capacity {
+        // it at-least doubles, which means it will be at-least `additional`
+        capacity
+    } else {
+        // This is the lowest number that is at-least half of the desired capacity.
+        // Thus, at-least double provides at-least the desired capacity.
+        additional
+            .checked_add(length)
+            .and_then(|v| v.checked_add(1))
+            .expect("Overflow")
+            / 2
+    }).expect("Overflow"))
+}
+
+/// Will allocate exactly enough memory to insure the storage has enough
+/// space for the specified number of additional items of the layout, or do
+/// nothing if it has already allocated enough.
+pub fn reserve_exact_alloc(storage: &mut Alloc, item: Layout, length: usize, additional: usize) {
+    if alloc_capacity(storage) - length >= additional {
+        return;
+    }
+    // X >= Z is always true when Z == 0, which would return
+    // Y + Z > 0 is always true when Z != 0
+    grow_alloc(storage, item, unsafe { NonZeroUsize::new_unchecked(
+        length.checked_add(additional).expect("Overflow")
+    )})
+}
+
+/// Allocates or reallocates the storage for the total number of items of
+/// the layout, which must not be zero-sized. Aborts if the allocation fails.
+///
+/// # Panics
+///
+/// Panics if the size overflows.
+pub fn grow_alloc(storage: &mut Alloc, item: Layout, total: NonZeroUsize) {
+    let layout = array_layout(item, total);
+
+    let ptr = if let Some((ptr, capacity)) = *storage {
+        unsafe {
+            realloc(ptr.as_ptr(), array_layout(item, capacity), layout.size())
+        }
+    } else {
+        unsafe {
+            alloc(layout)
+        }
+    };
+
+    if let Some(ptr) = NonNull::new(ptr) {
+        *storage = Some((ptr, total))
+    } else {
+        handle_alloc_error(layout)
+    }
+}
+
+/// Allocates, or returns a dangling pointer if the layout is zero-sized.
+/// Aborts if the allocation fails.
+pub fn alloc_layout(layout: Layout) -> NonNull<u8> {
+    if layout.size() == 0 {
+        return unsafe { NonNull::new_unchecked(layout.align() as *mut u8) };
+    }
+    match NonNull::new(unsafe { alloc(layout) }) {
+        Some(ptr) => ptr,
+        None => handle_alloc_error(layout),
+    }
+}
+
+/// Deallocates, unless the layout is zero-sized.
+///
+/// # Safety
+///
+/// The pointer must be from [`alloc_layout`] with the same layout.
+#[inline(always)]
+pub unsafe fn dealloc_layout(ptr: NonNull<u8>, layout: Layout) {
+    if layout.size() != 0 {
+        dealloc(ptr.as_ptr(), layout)
+    }
+}
+
+/// A new allocation that is freed without dropping its contents unless
+/// forgotten, such as when populating a box panics.
+pub(crate) struct Unboxed(pub(crate) NonNull<u8>, Layout);
+
+impl Unboxed {
+    /// Allocates for the layout, or dangles if the layout is zero-sized.
+    #[inline(always)]
+    pub(crate) fn new(layout: Layout) -> Self {
+        Unboxed(alloc_layout(layout), layout)
+    }
+}
+
+impl Drop for Unboxed {
+    fn drop(&mut self) {
+        unsafe { dealloc_layout(self.0, self.1) }
+    }
+}
+
+/// Uninitialized inline storage, aligned for any `Handle<T, [S]>`.
+#[repr(C)]
+pub struct Inline<T, S, const BYTES: usize> {
+    _align: [Handle<T, [S; 0]>; 0],
+    bytes: [MaybeUninit<u8>; BYTES],
+}
+
+impl<T, S, const BYTES: usize> Inline<T, S, BYTES> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Inline {
+            _align: [],
+            bytes: [MaybeUninit::uninit(); BYTES],
+        }
+    }
+
+    #[inline(always)]
+    pub fn ptr(&mut self) -> NonNull<u8> {
+        NonNull::from(&mut self.bytes).cast()
+    }
+
+    #[inline(always)]
+    pub fn ptr_const(&self) -> NonNull<u8> {
+        NonNull::from(&self.bytes).cast()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::mem;
@@ -19,6 +217,22 @@ mod test {
         )
     }
 
+    #[test]
+    fn inline_align() {
+        assert_eq!(
+            mem::align_of::<u64>(),
+            mem::align_of::<Inline<u8, u64, 3>>(),
+        );
+        assert_eq!(
+            mem::align_of::<u32>(),
+            mem::align_of::<Inline<u32, u8, 3>>(),
+        );
+        assert_eq!(
+            3,
+            mem::size_of::<Inline<u8, u8, 3>>(),
+        );
+    }
+
     #[test]
     fn same_align() {
         assert_eq!(
@@ -0,0 +1,97 @@
+use std::mem::size_of;
+use ndarray::{
+    ArrayView2,
+    ArrayViewMut2,
+    ShapeBuilder,
+};
+use crate::{
+    FixedVec,
+    Handle,
+};
+
+/// Returns the distance between tails in cells, if every item is a whole
+/// number of cells.
+fn tail_stride<T, S>(slice: usize) -> Option<usize> {
+    let size = size_of::<S>();
+    let item = Handle::<T, [S]>::size_slice(slice);
+    match item.checked_div(size) {
+        Some(stride) if stride * size == item => Some(stride),
+        _ => None,
+    }
+}
+
+impl<S> FixedVec<(), S> {
+    /// Views the cells as a two-dimensional array, with a row for each item
+    /// and a column for each cell of the tail.
+    pub fn as_array2(&self) -> ArrayView2<'_, S> {
+        ArrayView2::from_shape((self.len(), self.slice_length()), self.as_flat_slice())
+            .expect("Bad shape")
+    }
+
+    /// Views the cells as a mutable two-dimensional array, with a row for
+    /// each item and a column for each cell of the tail.
+    pub fn as_array2_mut(&mut self) -> ArrayViewMut2<'_, S> {
+        let shape = (self.len(), self.slice_length());
+        ArrayViewMut2::from_shape(shape, self.as_flat_slice_mut())
+            .expect("Bad shape")
+    }
+}
+
+impl<T, S> FixedVec<T, S> {
+    fn tail_start(&self) -> *mut S {
+        Handle::tail_ptr(self.as_ptr() as *mut Handle<T, [S]>) as *mut S
+    }
+
+    /// Views the tails as a two-dimensional array, with a row for each item
+    /// and a column for each cell, skipping over the values. Returns `None`
+    /// if the cells are zero-sized, or if the size of an item is not a
+    /// multiple of the size of a cell, as strides are counted in cells.
+    pub fn tails_array2(&self) -> Option<ArrayView2<'_, S>> {
+        let stride = tail_stride::<T, S>(self.slice_length())?;
+        let shape = (self.len(), self.slice_length()).strides((stride, 1));
+        Some(unsafe { ArrayView2::from_shape_ptr(shape, self.tail_start()) })
+    }
+
+    /// Views the tails as a mutable two-dimensional array. See
+    /// [`tails_array2`](Self::tails_array2).
+    pub fn tails_array2_mut(&mut self) -> Option<ArrayViewMut2<'_, S>> {
+        let stride = tail_stride::<T, S>(self.slice_length())?;
+        let shape = (self.len(), self.slice_length()).strides((stride, 1));
+        Some(unsafe { ArrayViewMut2::from_shape_ptr(shape, self.tail_start()) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn array2() {
+        let mut vec = FixedVec::<(), f64>::new(3);
+        for ix in 0..4 {
+            vec.push((), [ix as f64, 1.0, 2.0]);
+        }
+        assert_eq!(vec.as_array2().shape(), [4, 3]);
+        assert_eq!(vec.as_array2().column(0).sum(), 6.0);
+        vec.as_array2_mut()[[2, 1]] = 5.0;
+        assert_eq!(vec[(2, 1)], 5.0);
+    }
+
+    #[test]
+    fn tails() {
+        let mut vec = FixedVec::<u64, u32>::new(2);
+        for ix in 0..5 {
+            vec.push(ix, [ix as u32, 10]);
+        }
+        let tails = vec.tails_array2().unwrap();
+        assert_eq!(tails.strides(), [4, 1]);
+        assert_eq!(tails.column(0).sum(), 10);
+        assert_eq!(tails.row(3).to_vec(), [3, 10]);
+        vec.tails_array2_mut().unwrap()[[4, 1]] = 7;
+        assert_eq!(vec[4].tail, [4, 7]);
+        assert_eq!(vec[4].value, 4);
+
+        assert!(FixedVec::<u8, [u8; 3]>::new(1).tails_array2().is_none());
+        assert_eq!(FixedVec::<u8, u32>::new(1).tails_array2().unwrap().len(), 0);
+    }
+}
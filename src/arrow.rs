@@ -0,0 +1,157 @@
+use std::{
+    convert::TryFrom,
+    sync::Arc,
+};
+use arrow_array::{
+    Array,
+    ArrowPrimitiveType,
+    FixedSizeListArray,
+    PrimitiveArray,
+};
+use arrow_buffer::{
+    ArrowNativeType,
+    ScalarBuffer,
+};
+use arrow_schema::{
+    ArrowError,
+    Field,
+};
+use crate::FixedVec;
+
+/// Builds the array from the flat cells of the items.
+fn from_flat<P: ArrowPrimitiveType>(
+    flat: std::vec::Vec<P::Native>,
+    slice: usize,
+    length: usize,
+) -> Result<FixedSizeListArray, ArrowError> {
+    let size = i32::try_from(slice).map_err(|_| ArrowError::InvalidArgumentError(format!(
+        "slice length {} does not fit a FixedSizeListArray",
+        slice,
+    )))?;
+    let values = PrimitiveArray::<P>::new(ScalarBuffer::from(flat), None);
+    FixedSizeListArray::try_new_with_length(
+        Arc::new(Field::new_list_field(P::DATA_TYPE, false)),
+        size,
+        Arc::new(values),
+        None,
+        length,
+    )
+}
+
+impl<S: ArrowNativeType> FixedVec<(), S> {
+    /// Converts into an Arrow [`FixedSizeListArray`] of the primitive type,
+    /// without copying, as the layout is the same. The array has no nulls.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the tail length does not fit in an [`i32`].
+    pub fn into_arrow<P: ArrowPrimitiveType<Native=S>>(self) -> Result<FixedSizeListArray, ArrowError> {
+        let (slice, length) = (self.slice_length(), self.len());
+        from_flat::<P>(self.into_flat_vec(), slice, length)
+    }
+
+    /// Copies into an Arrow [`FixedSizeListArray`] of the primitive type.
+    /// The array has no nulls.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the tail length does not fit in an [`i32`].
+    pub fn to_arrow<P: ArrowPrimitiveType<Native=S>>(&self) -> Result<FixedSizeListArray, ArrowError> {
+        from_flat::<P>(self.as_flat_slice().to_vec(), self.slice_length(), self.len())
+    }
+
+    /// Converts from an Arrow [`FixedSizeListArray`] of the primitive type.
+    /// Reuses the values buffer when the array is its only owner and it was
+    /// allocated as a [`std::vec::Vec`], and otherwise copies.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the values are not of the primitive type, or if there are
+    /// any nulls.
+    pub fn from_arrow<P: ArrowPrimitiveType<Native=S>>(array: FixedSizeListArray) -> Result<Self, ArrowError> {
+        if array.null_count() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "FixedVec can not contain null lists".to_string(),
+            ));
+        }
+        let length = array.len();
+        let (_, size, values, _) = array.into_parts();
+        if values.data_type() != &P::DATA_TYPE {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "expected values of {}, found {}",
+                P::DATA_TYPE,
+                values.data_type(),
+            )));
+        }
+        if values.null_count() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "FixedVec can not contain null values".to_string(),
+            ));
+        }
+
+        let slice = size as usize;
+        if slice == 0 {
+            let mut vec = FixedVec::new(0);
+            unsafe { vec.set_len(length) };
+            return Ok(vec);
+        }
+        let data = values.to_data();
+        drop(values);
+        let (_, values, _) = PrimitiveArray::<P>::from(data).into_parts();
+        let flat = match values.into_inner().into_vec::<S>() {
+            Ok(flat) => flat,
+            Err(buffer) => buffer.typed_data::<S>().to_vec(),
+        };
+        FixedVec::from_flat_vec(flat, slice)
+            .map_err(|error| ArrowError::InvalidArgumentError(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow_array::types::{
+        Float64Type,
+        Int32Type,
+    };
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut vec = FixedVec::<(), i32>::new(3);
+        for ix in 0..4 {
+            vec.push((), [ix, ix * 10, ix * 100]);
+        }
+        let ptr = vec.as_flat_slice().as_ptr();
+        let array = vec.to_arrow::<Int32Type>().unwrap();
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.value_length(), 3);
+
+        let array = vec.clone().into_arrow::<Int32Type>().unwrap();
+        assert_eq!(FixedVec::from_arrow::<Int32Type>(array.slice(1, 2)).unwrap()[1].tail, [2, 20, 200]);
+        let array = vec.into_arrow::<Int32Type>().unwrap();
+        let vec = FixedVec::from_arrow::<Int32Type>(array).unwrap();
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec[(3, 2)], 300);
+        assert_eq!(vec.as_flat_slice().as_ptr(), ptr);
+
+        let mut empty = FixedVec::<(), f64>::new(0);
+        empty.push((), []);
+        let array = empty.into_arrow::<Float64Type>().unwrap();
+        assert_eq!(array.len(), 1);
+        assert_eq!(FixedVec::from_arrow::<Float64Type>(array).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn errors() {
+        let array = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![Some(vec![Some(1), Some(2)]), None],
+            2,
+        );
+        assert!(FixedVec::<(), i32>::from_arrow::<Int32Type>(array).is_err());
+        let array = FixedSizeListArray::from_iter_primitive::<Int32Type, _, _>(
+            vec![Some(vec![Some(1), None])],
+            2,
+        );
+        assert!(FixedVec::<(), i32>::from_arrow::<Int32Type>(array).is_err());
+    }
+}
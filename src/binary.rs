@@ -0,0 +1,254 @@
+use std::{
+    convert::TryFrom,
+    io::{
+        self,
+        Read,
+        Write,
+    },
+    mem::size_of,
+    ptr::{
+        slice_from_raw_parts_mut,
+        write_bytes,
+        NonNull,
+    },
+};
+use bytemuck::{
+    bytes_of,
+    cast_slice,
+    Pod,
+};
+use crate::{
+    error::FormatError,
+    FixedVec,
+    Handle,
+    pod::is_unpadded,
+};
+
+const MAGIC: [u8; 4] = *b"DSTV";
+const VERSION: u16 = 1;
+#[cfg(target_endian = "little")]
+const ENDIANNESS: u8 = 1;
+#[cfg(target_endian = "big")]
+const ENDIANNESS: u8 = 2;
+
+/// The header of the binary format, after the magic bytes, version and
+/// endianness. Every field is a little-endian `u64`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+struct Header {
+    slice_length: u64,
+    length: u64,
+    value_size: u64,
+    cell_size: u64,
+    item_size: u64,
+    alignment: u64,
+}
+
+impl Header {
+    fn new<T, S>(slice_length: usize, length: usize) -> Self {
+        Header {
+            slice_length: slice_length as u64,
+            length: length as u64,
+            value_size: size_of::<T>() as u64,
+            cell_size: size_of::<S>() as u64,
+            item_size: Handle::<T, [S]>::size_slice(slice_length) as u64,
+            alignment: Handle::<T, [S]>::alignment_slice(slice_length) as u64,
+        }
+    }
+
+    fn fields(&self) -> [(&'static str, u64); 6] {
+        [
+            ("slice length", self.slice_length),
+            ("length", self.length),
+            ("value size", self.value_size),
+            ("cell size", self.cell_size),
+            ("item size", self.item_size),
+            ("alignment", self.alignment),
+        ]
+    }
+
+    fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&[ENDIANNESS, 0])?;
+        for (_, field) in self.fields().iter() {
+            writer.write_all(&field.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn read(reader: &mut impl Read) -> Result<Self, FormatError> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(FormatError::Magic);
+        }
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version != VERSION {
+            return Err(FormatError::Version(version));
+        }
+        let mut endianness = [0; 2];
+        reader.read_exact(&mut endianness)?;
+        if endianness[0] != ENDIANNESS {
+            return Err(FormatError::Mismatch {
+                field: "endianness",
+                expected: ENDIANNESS as u64,
+                found: endianness[0] as u64,
+            });
+        }
+
+        let mut fields = [0; 6];
+        for field in fields.iter_mut() {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes)?;
+            *field = u64::from_le_bytes(bytes);
+        }
+        let [slice_length, length, value_size, cell_size, item_size, alignment] = fields;
+        Ok(Header {
+            slice_length,
+            length,
+            value_size,
+            cell_size,
+            item_size,
+            alignment,
+        })
+    }
+
+    /// Converts a field to a [`usize`], failing if it does not fit.
+    fn usize(field: &'static str, value: u64) -> Result<usize, FormatError> {
+        usize::try_from(value).map_err(|_| FormatError::Mismatch {
+            field,
+            expected: usize::MAX as u64,
+            found: value,
+        })
+    }
+}
+
+impl<T: Pod, S: Pod> FixedVec<T, S> {
+    /// Writes the items in a binary format, starting with a header that
+    /// describes the layout of the items. Padding is not written.
+    ///
+    /// The format is only meant to be read by [`read_from`](Self::read_from)
+    /// with the same types on a platform with the same endianness.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        Header::new::<T, S>(self.slice_length(), self.len()).write(writer)?;
+        if is_unpadded::<T, S>(self.slice_length()) {
+            return writer.write_all(self.as_bytes());
+        }
+        for handle in self {
+            writer.write_all(bytes_of(&handle.value))?;
+            writer.write_all(cast_slice(&handle.tail))?;
+        }
+        Ok(())
+    }
+
+    /// Reads items written by [`write_to`](Self::write_to).
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading fails, or if the header does not match the layout of
+    /// the items, such as when written with different types or on a platform
+    /// with different endianness. The length in the header is not trusted,
+    /// as the items grow while being read, so a truncated input fails
+    /// instead of reserving for items that are not there.
+    pub fn read_from(reader: &mut impl Read) -> Result<Self, FormatError> {
+        let header = Header::read(reader)?;
+        let slice_length = Header::usize("slice length", header.slice_length)?;
+        let length = Header::usize("length", header.length)?;
+        let expected = Header::new::<T, S>(slice_length, length);
+        for ((field, expected), (_, found)) in expected.fields().iter().zip(header.fields().iter()) {
+            if expected != found {
+                return Err(FormatError::Mismatch {
+                    field,
+                    expected: *expected,
+                    found: *found,
+                });
+            }
+        }
+
+        let mut vec = FixedVec::new(slice_length);
+        let size = Handle::<T, [S]>::size_slice(slice_length);
+        if size == 0 {
+            unsafe { vec.set_len(length) };
+            return Ok(vec);
+        }
+        let unpadded = is_unpadded::<T, S>(slice_length);
+        while vec.len() < length {
+            // Grows as items are read, rather than trusting the header
+            vec.reserve(1);
+            let start = vec.len();
+            let end = length.min(vec.capacity());
+            unsafe {
+                let ptr = NonNull::new_unchecked(vec.as_mut_ptr() as *mut u8);
+                let bytes = ptr.as_ptr().add(start * size);
+                // Zeroed, so that reading never sees uninitialized padding
+                write_bytes(bytes, 0, (end - start) * size);
+                if unpadded {
+                    reader.read_exact(&mut *slice_from_raw_parts_mut(bytes, (end - start) * size))?;
+                } else {
+                    for ix in start..end {
+                        let handle = get_ix!(T S, ptr, slice_length, ix,);
+                        reader.read_exact(&mut *slice_from_raw_parts_mut(
+                            Handle::value_ptr(handle) as *mut u8,
+                            size_of::<T>(),
+                        ))?;
+                        reader.read_exact(&mut *slice_from_raw_parts_mut(
+                            Handle::tail_ptr(handle) as *mut u8,
+                            slice_length * size_of::<S>(),
+                        ))?;
+                    }
+                }
+                vec.set_len(end);
+            }
+        }
+        Ok(vec)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn round_trip<T: Pod + Eq + std::fmt::Debug, S: Pod + Eq + std::fmt::Debug>(vec: &FixedVec<T, S>) {
+        let mut bytes = std::vec::Vec::new();
+        vec.write_to(&mut bytes).unwrap();
+        let read = FixedVec::<T, S>::read_from(&mut &bytes[..]).unwrap();
+        assert_eq!(&read, vec);
+    }
+
+    #[test]
+    fn binary() {
+        let mut unpadded = FixedVec::<u32, u16>::new(2);
+        let mut padded = FixedVec::<u32, u16>::new(3);
+        for ix in 0..10 {
+            unpadded.push(ix, [ix as u16; 2]);
+            padded.push(ix, [ix as u16 + 1; 3]);
+        }
+        round_trip(&unpadded);
+        round_trip(&padded);
+        round_trip(&FixedVec::<(), ()>::new(4));
+
+        let mut bytes = std::vec::Vec::new();
+        padded.write_to(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), 8 + 6 * 8 + 10 * 10);
+        match FixedVec::<u32, u32>::read_from(&mut &bytes[..]) {
+            Err(FormatError::Mismatch { field: "cell size", expected: 4, found: 2 }) => {},
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(FixedVec::<u32, u16>::read_from(&mut &bytes[..90]), Err(FormatError::Io(_))));
+        let mut corrupt = bytes.clone();
+        corrupt[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(matches!(FixedVec::<u32, u16>::read_from(&mut &corrupt[..]), Err(FormatError::Io(_))));
+
+        let mut empty = std::vec::Vec::new();
+        FixedVec::<(), ()>::new(2).write_to(&mut empty).unwrap();
+        empty[16..24].copy_from_slice(&(usize::MAX as u64).to_le_bytes());
+        let read = FixedVec::<(), ()>::read_from(&mut &empty[..]).unwrap();
+        assert_eq!(read.len(), usize::MAX);
+        // Dropping does not visit each of the zero-sized items
+        drop(read);
+        bytes[0] = 0;
+        assert!(matches!(FixedVec::<u32, u16>::read_from(&mut &bytes[..]), Err(FormatError::Magic)));
+    }
+}
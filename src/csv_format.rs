@@ -0,0 +1,240 @@
+use std::{
+    fmt::Display,
+    io::{
+        Read,
+        Write,
+    },
+    str::FromStr,
+};
+use csv::{
+    ReaderBuilder,
+    StringRecord,
+    Writer,
+    WriterBuilder,
+};
+use crate::{
+    error::CsvError,
+    FixedVec,
+};
+
+/// How a CSV is read or written.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CsvOptions {
+    /// The field delimiter, `,` by default.
+    pub delimiter: u8,
+    /// The quote character, `"` by default.
+    pub quote: u8,
+    /// Whether the first row is a header, `true` by default. When reading,
+    /// the header row determines the tail length even if there are no other
+    /// rows.
+    pub has_headers: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            quote: b'"',
+            has_headers: true,
+        }
+    }
+}
+
+impl CsvOptions {
+    fn reader<R: Read>(&self, reader: R) -> csv::Reader<R> {
+        ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .has_headers(self.has_headers)
+            .flexible(true)
+            .from_reader(reader)
+    }
+
+    fn writer<W: Write>(&self, writer: W) -> Writer<W> {
+        WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .from_writer(writer)
+    }
+}
+
+fn parse<V: FromStr>(record: &StringRecord, field: usize) -> Result<V, CsvError>
+where
+    V::Err: Display,
+{
+    record[field].parse().map_err(|error: V::Err| CsvError::Parse {
+        line: record.position().map_or(0, |position| position.line()),
+        field,
+        message: error.to_string(),
+    })
+}
+
+/// Reads every row, where the first `labels` fields are parsed as the
+/// value, and the rest are parsed as the tail.
+fn read<H, S, R: Read>(
+    reader: R,
+    options: &CsvOptions,
+    labels: usize,
+    mut value: impl FnMut(&StringRecord) -> Result<H, CsvError>,
+) -> Result<FixedVec<H, S>, CsvError>
+where
+    S: FromStr,
+    S::Err: Display,
+{
+    let mut reader = options.reader(reader);
+    let headers = if options.has_headers {
+        Some(reader.headers()?.len())
+    } else {
+        None
+    };
+    let mut records = reader.into_records().peekable();
+    let fields = match (headers, records.peek()) {
+        (Some(fields), _) => fields,
+        (None, Some(Ok(record))) => record.len(),
+        (None, _) => labels,
+    };
+
+    let mut vec = FixedVec::new(fields.saturating_sub(labels));
+    for record in records {
+        let record = record?;
+        if record.len() != fields || fields < labels {
+            return Err(CsvError::Ragged {
+                line: record.position().map_or(0, |position| position.line()),
+                expected: fields,
+                found: record.len(),
+            });
+        }
+        let value = value(&record)?;
+        let tail = (labels..fields)
+            .map(|field| parse(&record, field))
+            .collect::<Result<std::vec::Vec<S>, _>>()?;
+        vec.push(value, tail);
+    }
+    Ok(vec)
+}
+
+impl<H: FromStr, S: FromStr> FixedVec<H, S>
+where
+    H::Err: Display,
+    S::Err: Display,
+{
+    /// Reads a CSV where the first field of every row is a label parsed as
+    /// the value, and the remaining fields are parsed as the tail. The tail
+    /// length is determined by the header row, or by the first row if there
+    /// is no header. Quoting and escaping are handled as configured.
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading fails, if a row has a different number of fields,
+    /// or if a field does not parse. Errors include the line number.
+    pub fn from_csv_reader(reader: impl Read, options: &CsvOptions) -> Result<Self, CsvError> {
+        read(reader, options, 1, |record| parse(record, 0))
+    }
+}
+
+impl<S: FromStr> FixedVec<(), S>
+where
+    S::Err: Display,
+{
+    /// Reads a CSV where every field is parsed as the tail. See
+    /// [`from_csv_reader`](FixedVec::from_csv_reader).
+    ///
+    /// # Errors
+    ///
+    /// Fails if reading fails, if a row has a different number of fields,
+    /// or if a field does not parse. Errors include the line number.
+    pub fn from_csv_reader_unlabeled(reader: impl Read, options: &CsvOptions) -> Result<Self, CsvError> {
+        read(reader, options, 0, |_| Ok(()))
+    }
+}
+
+impl<H: Display, S: Display> FixedVec<H, S> {
+    /// Writes a CSV where the first field of every row is the value, followed
+    /// by the tail. The header row is written when provided, and must have a
+    /// field for the value and for each cell.
+    ///
+    /// # Errors
+    ///
+    /// Fails if writing fails.
+    pub fn write_csv(&self, writer: impl Write, headers: Option<&[&str]>, options: &CsvOptions) -> Result<(), CsvError> {
+        let mut writer = options.writer(writer);
+        if let Some(headers) = headers {
+            writer.write_record(headers)?;
+        }
+        for handle in self {
+            writer.write_field(handle.value.to_string())?;
+            for cell in handle.tail.iter() {
+                writer.write_field(cell.to_string())?;
+            }
+            writer.write_record(None::<&[u8]>)?;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+}
+
+impl<S: Display> FixedVec<(), S> {
+    /// Writes a CSV where every field of every row is the tail. The header
+    /// row is written when provided, and must have a field for each cell.
+    ///
+    /// # Errors
+    ///
+    /// Fails if writing fails.
+    pub fn write_csv_unlabeled(&self, writer: impl Write, headers: Option<&[&str]>, options: &CsvOptions) -> Result<(), CsvError> {
+        let mut writer = options.writer(writer);
+        if let Some(headers) = headers {
+            writer.write_record(headers)?;
+        }
+        for handle in self {
+            writer.write_record(handle.tail.iter().map(|cell| cell.to_string()))?;
+        }
+        writer.flush().map_err(csv::Error::from)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let input = "name,x,y\n\"a, b\",1,2\nc,3,4\n";
+        let vec = FixedVec::<String, u32>::from_csv_reader(input.as_bytes(), &CsvOptions::default()).unwrap();
+        assert_eq!(vec.slice_length(), 2);
+        assert_eq!(vec[0].value, "a, b");
+        assert_eq!(vec[1].tail, [3, 4]);
+
+        let mut output = std::vec::Vec::new();
+        vec.write_csv(&mut output, Some(&["name", "x", "y"]), &CsvOptions::default()).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), input);
+
+        let options = CsvOptions {
+            delimiter: b';',
+            has_headers: false,
+            ..CsvOptions::default()
+        };
+        let vec = FixedVec::<(), f32>::from_csv_reader_unlabeled("1;2.5\n3;4\n".as_bytes(), &options).unwrap();
+        assert_eq!(vec[1].tail, [3.0, 4.0]);
+        let mut output = std::vec::Vec::new();
+        vec.write_csv_unlabeled(&mut output, None, &options).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "1;2.5\n3;4\n");
+
+        let empty = FixedVec::<(), u8>::from_csv_reader_unlabeled("a,b,c\n".as_bytes(), &CsvOptions::default()).unwrap();
+        assert_eq!(empty.slice_length(), 3);
+    }
+
+    #[test]
+    fn errors() {
+        let ragged = FixedVec::<String, u32>::from_csv_reader("name,x\na,1\nb,2,3\n".as_bytes(), &CsvOptions::default());
+        match ragged {
+            Err(CsvError::Ragged { line: 3, expected: 2, found: 3 }) => {},
+            other => panic!("{:?}", other),
+        }
+        let parse = FixedVec::<String, u32>::from_csv_reader("name,x\na,1\nb,c\n".as_bytes(), &CsvOptions::default());
+        match parse {
+            Err(CsvError::Parse { line: 3, field: 1, .. }) => {},
+            other => panic!("{:?}", other),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    error::Error,
+    fmt::{
+        self,
+        Display,
+    },
+};
+#[cfg(feature = "bytemuck")]
+use std::io;
+
+/// The tails of the items to convert are not all the same length.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RaggedError {
+    /// The index of the first item with a different tail length.
+    pub index: usize,
+    /// The tail length of the first item.
+    pub expected: usize,
+    /// The tail length of the item at the index.
+    pub found: usize,
+}
+
+impl Display for RaggedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "item {} has a tail length of {}, but expected {}",
+            self.index,
+            self.found,
+            self.expected,
+        )
+    }
+}
+
+impl Error for RaggedError {}
+
+/// The length of a flat buffer is not a multiple of the tail length.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct FlatLengthError {
+    /// The length of the flat buffer.
+    pub length: usize,
+    /// The tail length of each item.
+    pub slice_length: usize,
+}
+
+impl Display for FlatLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "flat length of {} is not a multiple of the slice length {}",
+            self.length,
+            self.slice_length,
+        )
+    }
+}
+
+impl Error for FlatLengthError {}
+
+/// Bytes can not be reinterpreted as items.
+#[cfg(feature = "bytemuck")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CastError {
+    /// The bytes do not start at a multiple of the item alignment.
+    Misaligned {
+        /// The required alignment.
+        align: usize,
+    },
+    /// The number of bytes is not a multiple of the item size.
+    Size {
+        /// The number of bytes.
+        length: usize,
+        /// The required size of each item.
+        size: usize,
+    },
+}
+
+#[cfg(feature = "bytemuck")]
+impl Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CastError::Misaligned { align } => write!(
+                f,
+                "bytes are not aligned to {}",
+                align,
+            ),
+            CastError::Size { length, size } => write!(
+                f,
+                "byte length of {} is not a multiple of the item size {}",
+                length,
+                size,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl Error for CastError {}
+
+/// The binary format of a [`FixedVec`](crate::FixedVec) can not be read.
+#[cfg(feature = "bytemuck")]
+#[derive(Debug)]
+pub enum FormatError {
+    /// Reading failed.
+    Io(io::Error),
+    /// The data does not start with the expected magic bytes.
+    Magic,
+    /// The version of the format is not supported.
+    Version(u16),
+    /// A field of the header does not match the items being read.
+    Mismatch {
+        /// The name of the header field.
+        field: &'static str,
+        /// The value for the items being read.
+        expected: u64,
+        /// The value in the header.
+        found: u64,
+    },
+}
+
+#[cfg(feature = "bytemuck")]
+impl Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Io(error) => Display::fmt(error, f),
+            FormatError::Magic => f.write_str("bad magic bytes"),
+            FormatError::Version(version) => write!(
+                f,
+                "unsupported version {}",
+                version,
+            ),
+            FormatError::Mismatch { field, expected, found } => write!(
+                f,
+                "header {} is {}, but expected {}",
+                field,
+                found,
+                expected,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl From<io::Error> for FormatError {
+    #[inline(always)]
+    fn from(error: io::Error) -> Self {
+        FormatError::Io(error)
+    }
+}
+
+/// A CSV can not be read or written.
+#[cfg(feature = "csv")]
+#[derive(Debug)]
+pub enum CsvError {
+    /// Reading, writing, or parsing the CSV format failed.
+    Csv(csv::Error),
+    /// A row does not have the same number of fields as the first row.
+    Ragged {
+        /// The line of the row, starting at 1.
+        line: u64,
+        /// The number of fields in the first row.
+        expected: usize,
+        /// The number of fields in the row.
+        found: usize,
+    },
+    /// A field could not be parsed.
+    Parse {
+        /// The line of the row, starting at 1.
+        line: u64,
+        /// The index of the field in the row.
+        field: usize,
+        /// The parsing error.
+        message: String,
+    },
+}
+
+#[cfg(feature = "csv")]
+impl Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(error) => Display::fmt(error, f),
+            CsvError::Ragged { line, expected, found } => write!(
+                f,
+                "line {} has {} fields, but expected {}",
+                line,
+                found,
+                expected,
+            ),
+            CsvError::Parse { line, field, message } => write!(
+                f,
+                "line {} field {}: {}",
+                line,
+                field,
+                message,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for CsvError {
+    #[inline(always)]
+    fn from(error: csv::Error) -> Self {
+        CsvError::Csv(error)
+    }
+}
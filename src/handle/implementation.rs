@@ -6,13 +6,36 @@ use std::{
     ptr::{
         null,
         slice_from_raw_parts,
+        slice_from_raw_parts_mut,
         write,
     },
 };
-use crate::util::can_try_alloc;
+use crate::{
+    alloc::{
+        array_layout,
+        Unboxed,
+    },
+    DstTail,
+};
 use super::Handle;
 
 impl<T, S> Handle<T, [S]> {
+    /// Allocates a single item with a tail of `slice` cells, taken from the
+    /// iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count, in which case
+    /// the value and any taken cells are leaked.
+    pub fn new_boxed(slice: usize, value: T, tail: impl IntoIterator<Item=S>) -> Box<Self> {
+        let layout = Self::layout_slice(slice, NonZeroUsize::new(1).unwrap());
+        let guard = Unboxed::new(layout);
+        let handle = slice_from_raw_parts_mut(guard.0.as_ptr() as *mut S, slice) as *mut Self;
+        Self::populate(handle, slice..(slice + 1), value, &mut tail.into_iter());
+        mem::forget(guard);
+        unsafe { Box::from_raw(handle) }
+    }
+
     /// Returns the number of items inserted.
     ///
     /// # Panics
@@ -42,12 +65,14 @@ impl<T, S> Handle<T, [S]> {
         slice: &mut impl Iterator<Item=S>,
     ) -> usize {
         let slice_values = Self::tail_ptr(handle) as *mut S;
+        // The range is exclusive, so the last value is the most that fits
+        let end = slice_len.end - 1;
 
-        for offset in start..(slice_len.end) {
+        for offset in start..end {
             if let Some(value) = slice.next() {
                 unsafe {
                     write(
-                        slice_values.offset(offset as isize),
+                        slice_values.add(offset),
                         value,
                     )
                 }
@@ -58,21 +83,11 @@ impl<T, S> Handle<T, [S]> {
                 return offset;
             }
         }
-        slice_len.end
+        end
     }
 
     pub(crate) fn layout_slice(slice_size: usize, count: NonZeroUsize) -> Layout {
-        let size = Self::size_slice(slice_size)
-            .checked_mul(count.get())
-            .expect("Overflow");
-        if !can_try_alloc(size) {
-            panic!("Overflow");
-        }
-        Layout::from_size_align(
-            size,
-            Self::alignment_slice(slice_size),
-        )
-            .expect("Bad Layout")
+        array_layout(<[S]>::layout::<T>(slice_size), count)
     }
 }
 
@@ -1,5 +1,12 @@
 mod traits;
 mod implementation;
+mod tail;
+
+pub use tail::{
+    CloneTail,
+    Dst,
+    DstTail,
+};
 
 /// This struct contains a value and the unsized-tail. It cannot be
 /// normally instantiated.
@@ -12,6 +19,45 @@ pub struct Handle<V, T: ?Sized> {
 mod test {
     use super::*;
 
+    #[test]
+    fn boxed() {
+        use std::{
+            borrow::Cow,
+            collections::HashMap,
+            rc::Rc,
+        };
+        use crate::FixedVec;
+
+        let counter = Rc::new(());
+        let boxed = Handle::<Rc<()>, [Rc<()>]>::new_boxed(2, counter.clone(), vec![counter.clone(); 2]);
+        let copy = boxed.clone();
+        assert_eq!(Rc::strong_count(&counter), 7);
+        drop((boxed, copy));
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let mut vec = FixedVec::<u8, u16>::new(2);
+        vec.push(1, [2, 3]);
+        vec.push(4, [5, 6]);
+        let mut map = HashMap::new();
+        map.insert(vec[1].to_owned(), "second");
+        assert_eq!(map.get(&vec[1]), Some(&"second"));
+        assert_eq!(map.get(&vec[0]), None);
+
+        let mut cow = Cow::Borrowed(&vec[0]);
+        cow.to_mut().tail[1] = 9;
+        assert_eq!(cow.tail, [2, 9]);
+        assert_eq!(vec[0].tail, [2, 3]);
+
+        let empty = Handle::<(), [()]>::new_boxed(0, (), None);
+        assert_eq!(empty.clone().tail.len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough values to populate handle")]
+    fn boxed_short() {
+        Handle::<u8, [u8]>::new_boxed(3, 0, [1, 2]);
+    }
+
     #[test]
     fn size_check() {
         use std::mem::size_of;
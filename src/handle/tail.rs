@@ -0,0 +1,177 @@
+use std::{
+    alloc::Layout,
+    mem,
+    ptr::{
+        copy_nonoverlapping,
+        null_mut,
+        slice_from_raw_parts_mut,
+        write,
+    },
+};
+use crate::{
+    alloc::Unboxed,
+    prelude::*,
+};
+use super::Handle;
+
+/// An unsized type that can be the tail of a [`Handle`] in a
+/// [`TailVec`](crate::TailVec). All tails in one collection share the same
+/// [`Metadata`](DstTail::Metadata), which is what makes every item the same
+/// size.
+///
+/// This only describes where a tail is and how large it is. Populating one
+/// in place is left to [`CloneTail`], [`Dst`], and the unsafe
+/// [`TailVec::push_with`](crate::TailVec::push_with). Trait objects are not
+/// supported, as their metadata can not be known before a value exists. The
+/// other collections of this crate only hold slice tails.
+///
+/// # Safety
+///
+/// [`handle_ptr`](DstTail::handle_ptr) must return a pointer to the address
+/// provided, whose tail is described by the metadata provided, and
+/// [`metadata`](DstTail::metadata) must return that same metadata for any
+/// tail behind such a pointer.
+///
+/// If [`layout`](DstTail::layout) is overridden, it must return the same
+/// size and alignment as [`Layout::for_value`] would for a handle behind the
+/// pointer from [`handle_ptr`](DstTail::handle_ptr) with that metadata.
+pub unsafe trait DstTail {
+    /// What all tails of a collection share, such as the length of a slice.
+    type Metadata: Copy + Eq + Debug;
+
+    /// Returns the metadata describing this tail.
+    fn metadata(&self) -> Self::Metadata;
+
+    /// Creates a pointer to a handle at the address, with a tail described by
+    /// the metadata. The address may be null or dangling.
+    fn handle_ptr<V>(ptr: *mut u8, metadata: Self::Metadata) -> *mut Handle<V, Self>;
+
+    /// Returns the layout of a handle with a tail described by the metadata.
+    /// The size is always a multiple of the alignment, as for any Rust type.
+    fn layout<V>(metadata: Self::Metadata) -> Layout {
+        let ptr = Self::handle_ptr::<V>(null_mut(), metadata);
+        Layout::from_size_align(Handle::size(ptr), Handle::alignment(ptr))
+            .expect("Bad Layout")
+    }
+}
+
+/// A [`DstTail`] that can be populated in place by cloning another tail.
+///
+/// # Safety
+///
+/// [`clone_to`](CloneTail::clone_to) must fully initialize the destination.
+pub unsafe trait CloneTail: DstTail {
+    /// Writes a clone of this tail to the destination.
+    ///
+    /// # Safety
+    ///
+    /// The destination must be valid for writes of a tail with the same
+    /// metadata as this tail, and must not hold a live tail.
+    unsafe fn clone_to(&self, tail: *mut Self);
+}
+
+/// A user-defined struct whose last field is a slice, such as
+/// `struct Row { id: u32, cells: [f64] }`, which can be stored directly as
+/// the tail of a collection. Implement with `#[derive(Dst)]` from the
+/// `derive` flag, which requires the struct to be `#[repr(C)]`.
+///
+/// # Safety
+///
+/// [`value_layout`](Dst::value_layout) must be the exact layout of the
+/// struct with a slice of the length, [`value_ptr`](Dst::value_ptr) must
+/// return a pointer to the struct with a slice of the length, and
+/// [`init`](Dst::init) must fully initialize the struct, or panic.
+pub unsafe trait Dst: DstTail<Metadata = usize> {
+    /// The fields before the slice, as a tuple.
+    type Fields;
+    /// The type of the cells of the slice.
+    type Cell;
+
+    /// Returns the layout of the struct with a slice of the length.
+    fn value_layout(length: usize) -> Layout;
+
+    /// Creates a pointer to the struct at the address, with a slice of the
+    /// length.
+    fn value_ptr(ptr: *mut u8, length: usize) -> *mut Self;
+
+    /// Writes the fields and the cells taken from the iterator.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for writes of the struct with a slice of the
+    /// length, and must not hold a live struct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count, in which case
+    /// the fields and any taken cells are leaked.
+    unsafe fn init(
+        ptr: *mut Self,
+        length: usize,
+        fields: Self::Fields,
+        cells: &mut impl Iterator<Item=Self::Cell>,
+    );
+
+    /// Allocates the struct with a slice of the length, populated by the
+    /// fields and the cells taken from the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count, in which case
+    /// the fields and any taken cells are leaked.
+    fn new_boxed(
+        length: usize,
+        fields: Self::Fields,
+        cells: impl IntoIterator<Item=Self::Cell>,
+    ) -> Box<Self> {
+        let guard = Unboxed::new(Self::value_layout(length));
+        let ptr = Self::value_ptr(guard.0.as_ptr(), length);
+        unsafe { Self::init(ptr, length, fields, &mut cells.into_iter()) };
+        mem::forget(guard);
+        unsafe { Box::from_raw(ptr) }
+    }
+}
+
+unsafe impl<S> DstTail for [S] {
+    type Metadata = usize;
+
+    #[inline(always)]
+    fn metadata(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn handle_ptr<V>(ptr: *mut u8, metadata: usize) -> *mut Handle<V, Self> {
+        slice_from_raw_parts_mut(ptr as *mut S, metadata) as *mut Handle<V, Self>
+    }
+}
+
+unsafe impl<S: Clone> CloneTail for [S] {
+    unsafe fn clone_to(&self, tail: *mut Self) {
+        let tail = tail as *mut S;
+        for (offset, value) in self.iter().enumerate() {
+            write(tail.add(offset), value.clone());
+        }
+    }
+}
+
+unsafe impl DstTail for str {
+    type Metadata = usize;
+
+    #[inline(always)]
+    fn metadata(&self) -> usize {
+        self.len()
+    }
+
+    #[inline(always)]
+    fn handle_ptr<V>(ptr: *mut u8, metadata: usize) -> *mut Handle<V, Self> {
+        slice_from_raw_parts_mut(ptr, metadata) as *mut Handle<V, Self>
+    }
+}
+
+unsafe impl CloneTail for str {
+    #[inline(always)]
+    unsafe fn clone_to(&self, tail: *mut Self) {
+        copy_nonoverlapping(self.as_ptr(), tail as *mut u8, self.len())
+    }
+}
@@ -14,6 +14,26 @@ impl<
 
 impl<V: Eq, T: Eq + ?Sized> Eq for Handle<V, T> {}
 
+impl<
+    VL: PartialOrd<VR>, TL: PartialOrd<TR> + ?Sized,
+    VR, TR: ?Sized,
+> PartialOrd<Handle<VR, TR>> for Handle<VL, TL>
+{
+    fn partial_cmp(&self, other: &Handle<VR, TR>) -> Option<Ordering> {
+        match self.value.partial_cmp(&other.value)? {
+            Ordering::Equal => self.tail.partial_cmp(&other.tail),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<V: Ord, T: Ord + ?Sized> Ord for Handle<V, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+            .then_with(|| self.tail.cmp(&other.tail))
+    }
+}
+
 impl<V: Hash, T: Hash + ?Sized> Hash for Handle<V, T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.value.hash(state);
@@ -31,6 +51,21 @@ impl<V: Debug, T: Debug + ?Sized> Debug for Handle<V, T> {
     }
 }
 
+impl<T: Clone, S: Clone> ToOwned for Handle<T, [S]> {
+    type Owned = Box<Self>;
+
+    fn to_owned(&self) -> Box<Self> {
+        Self::new_boxed(self.tail.len(), self.value.clone(), self.tail.iter().cloned())
+    }
+}
+
+impl<T: Clone, S: Clone> Clone for Box<Handle<T, [S]>> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        (**self).to_owned()
+    }
+}
+
 impl<V, T: ?Sized> AsRef<T> for Handle<V, T> {
     #[inline(always)]
     fn as_ref(&self) -> &T {
@@ -17,8 +17,18 @@
 //! in a contiguous allocation. Some applications may have a performance
 //! benefit for using a contiguous allocation as opposed to multiple pointers.
 //!
-//! Currently, the only implementation is a `Vec` where the DST-tails are
-//! slices of the same length.
+//! Currently, the implementations are based on a `Vec` where the DST-tails
+//! are slices of the same length:
+//!
+//! * `FixedVec`, the `Vec` itself.
+//! * `FixedBinaryHeap`, a priority queue storing its items in a `FixedVec`.
+//! * `SegmentedFixedVec`, a `FixedVec` that never moves its items when growing.
+//! * `SmallFixedVec`, a `FixedVec` that stores a few items inline before
+//!   allocating.
+//! * `ArrayFixedVec`, a `FixedVec` with a fixed capacity that never allocates.
+//! * `FixedVec2`, a `FixedVec` where every item has two independently typed tails.
+//! * `TailVec`, a vec of items whose tails may be a `str` or a user type
+//!   implementing `DstTail`, without the slice operations of `FixedVec`.
 
 #[cfg(
     any(
@@ -63,17 +73,81 @@ macro_rules! get_ix {
     };
 }
 
+#[cfg(all(test, feature = "derive"))]
+extern crate self as dst;
+
 mod prelude;
+mod error;
 mod vecs;
 mod handle;
 mod alloc;
 mod util;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "bytemuck")]
+mod binary;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "csv")]
+mod csv_format;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "ndarray")]
+mod array2;
 
-pub use handle::Handle;
+#[cfg(feature = "bytemuck")]
+pub use error::{
+    CastError,
+    FormatError,
+};
+#[cfg(feature = "csv")]
+pub use csv_format::CsvOptions;
+#[cfg(feature = "csv")]
+pub use error::CsvError;
+pub use error::{
+    FlatLengthError,
+    RaggedError,
+};
+pub use handle::{
+    CloneTail,
+    Dst,
+    DstTail,
+    Handle,
+};
+#[cfg(feature = "derive")]
+pub use dst_derive::Dst;
 pub use vecs::{
+    array::Vec as ArrayFixedVec,
+    double::{
+        Iter as FixedVec2Iter,
+        IterMut as FixedVec2IterMut,
+        Vec as FixedVec2,
+    },
     fixed::{
         Vec as FixedVec,
         Iter as FixedVecIter,
+        Column as FixedVecColumn,
         IterMut as FixedVecIterMut,
+        TailDrain as FixedVecTailDrain,
+        TailInit as FixedVecTailInit,
+        View as FixedView,
+        ViewMut as FixedViewMut,
+    },
+    heap::{
+        BinaryHeap as FixedBinaryHeap,
+        Compare,
+        PeekMut as FixedBinaryHeapPeekMut,
+        ValueOrder,
+    },
+    segmented::{
+        Iter as SegmentedFixedVecIter,
+        IterMut as SegmentedFixedVecIterMut,
+        Vec as SegmentedFixedVec,
+    },
+    small::Vec as SmallFixedVec,
+    tail::{
+        Iter as TailVecIter,
+        IterMut as TailVecIterMut,
+        Vec as TailVec,
     },
 };
@@ -0,0 +1,192 @@
+use std::{
+    mem::size_of,
+    ptr::{
+        NonNull,
+        slice_from_raw_parts,
+        slice_from_raw_parts_mut,
+    },
+};
+use bytemuck::Pod;
+use crate::{
+    error::CastError,
+    FixedVec,
+    FixedView,
+    FixedViewMut,
+    Handle,
+};
+
+/// Checks the bytes against the alignment of [`Handle::layout_slice`].
+fn check_align<T, S>(bytes: &[u8], slice: usize) -> Result<(), CastError> {
+    let align = Handle::<T, [S]>::alignment_slice(slice);
+    // The alignment is always a power of two
+    if bytes.as_ptr() as usize & (align - 1) != 0 {
+        Err(CastError::Misaligned { align })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks the bytes against the layout of [`Handle::layout_slice`],
+/// returning the number of items.
+fn cast_items<T, S>(bytes: &[u8], slice: usize) -> Result<usize, CastError> {
+    check_align::<T, S>(bytes, slice)?;
+    let size = Handle::<T, [S]>::size_slice(slice);
+    let length = bytes.len();
+    match length.checked_div(size) {
+        Some(items) if items * size == length => Ok(items),
+        None if length == 0 => Ok(0),
+        _ => Err(CastError::Size { length, size }),
+    }
+}
+
+/// Checks that the items have no padding, as padding is never initialized.
+#[inline(always)]
+pub(crate) fn is_unpadded<T, S>(slice: usize) -> bool {
+    Handle::<T, [S]>::size_slice(slice) == size_of::<T>() + slice * size_of::<S>()
+}
+
+/// Checks that the items have no padding, as padding is never initialized.
+///
+/// # Panics
+///
+/// Panics if there is padding.
+fn assert_unpadded<T, S>(slice: usize) {
+    if !is_unpadded::<T, S>(slice) {
+        panic!("Padded handle");
+    }
+}
+
+/// Checks that the bytes are exactly one item.
+///
+/// # Panics
+///
+/// Panics if the bytes are misaligned or not the size of one item.
+fn assert_item<T, S>(bytes: &[u8], slice: usize) {
+    if let Err(error) = check_align::<T, S>(bytes, slice) {
+        panic!("{}", error);
+    }
+    let size = Handle::<T, [S]>::size_slice(slice);
+    if bytes.len() != size {
+        panic!("byte length of {} is not the item size {}", bytes.len(), size);
+    }
+}
+
+impl<T: Pod, S: Pod> FixedVec<T, S> {
+    /// Returns the bytes of all items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items have any padding, as padding is uninitialized.
+    pub fn as_bytes(&self) -> &[u8] {
+        assert_unpadded::<T, S>(self.slice_length());
+        let length = self.len() * self.item_size();
+        unsafe { &*slice_from_raw_parts(self.as_ptr() as *const u8, length) }
+    }
+
+    /// Returns the bytes of all items mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the items have any padding, as padding is uninitialized.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        assert_unpadded::<T, S>(self.slice_length());
+        let length = self.len() * self.item_size();
+        unsafe { &mut *slice_from_raw_parts_mut(self.as_mut_ptr() as *mut u8, length) }
+    }
+}
+
+impl<T: Pod, S: Pod> Handle<T, [S]> {
+    /// Reinterprets bytes as an item with the tail length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are misaligned or not exactly the size of the item.
+    pub fn from_bytes(bytes: &[u8], slice_length: usize) -> &Self {
+        assert_item::<T, S>(bytes, slice_length);
+        unsafe { &*(slice_from_raw_parts(bytes.as_ptr() as *const S, slice_length) as *const Self) }
+    }
+
+    /// Reinterprets bytes as a mutable item with the tail length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are misaligned or not exactly the size of the item.
+    pub fn from_bytes_mut(bytes: &mut [u8], slice_length: usize) -> &mut Self {
+        assert_item::<T, S>(bytes, slice_length);
+        unsafe { &mut *(slice_from_raw_parts_mut(bytes.as_mut_ptr() as *mut S, slice_length) as *mut Self) }
+    }
+}
+
+impl<'a, T: Pod, S: Pod> FixedView<'a, T, S> {
+    /// Reinterprets bytes as items with the tail length, without copying.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes are misaligned or not a multiple of the item size.
+    pub fn cast_from_bytes(bytes: &'a [u8], slice_length: usize) -> Result<Self, CastError> {
+        Ok(FixedView {
+            length: cast_items::<T, S>(bytes, slice_length)?,
+            ptr: NonNull::from(bytes).cast(),
+            slice: slice_length,
+            _phantom: Default::default(),
+        })
+    }
+}
+
+impl<'a, T: Pod, S: Pod> FixedViewMut<'a, T, S> {
+    /// Reinterprets bytes as mutable items with the tail length, without
+    /// copying.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the bytes are misaligned or not a multiple of the item size.
+    pub fn cast_from_bytes_mut(bytes: &'a mut [u8], slice_length: usize) -> Result<Self, CastError> {
+        Ok(FixedViewMut {
+            length: cast_items::<T, S>(bytes, slice_length)?,
+            ptr: NonNull::from(bytes).cast(),
+            slice: slice_length,
+            _phantom: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        let mut vec = FixedVec::<u32, u16>::new(2);
+        vec.push(1, [2, 3]);
+        vec.push(4, [5, 6]);
+        assert_eq!(vec.as_bytes().len(), 16);
+        // Copied to words, so that the bytes are aligned for the items
+        let mut words = vec![0u32; 4];
+        bytemuck::cast_slice_mut(&mut words).copy_from_slice(vec.as_bytes());
+        vec.as_bytes_mut()[8..12].copy_from_slice(&7u32.to_ne_bytes());
+        assert_eq!(vec[1].value, 7);
+
+        let bytes: &[u8] = bytemuck::cast_slice(&words);
+        let view = FixedView::<u32, u16>::cast_from_bytes(bytes, 2).unwrap();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view[1].tail, [5, 6]);
+        assert_eq!(Handle::<u32, [u16]>::from_bytes(&bytes[..8], 2).value, 1);
+
+        assert_eq!(
+            FixedView::<u32, u16>::cast_from_bytes(&bytes[1..9], 2).unwrap_err(),
+            CastError::Misaligned { align: 4 },
+        );
+        assert_eq!(
+            FixedView::<u32, u16>::cast_from_bytes(&bytes[..12], 2).unwrap_err(),
+            CastError::Size { length: 12, size: 8 },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Padded handle")]
+    fn padded() {
+        let mut vec = FixedVec::<u32, u16>::new(1);
+        vec.push(1, [2]);
+        vec.as_bytes();
+    }
+}
@@ -1,4 +1,5 @@
 pub use std::{
+    cmp::Ordering,
     fmt::{
         self,
         Debug,
@@ -0,0 +1,159 @@
+use std::{
+    convert::TryFrom,
+    marker::PhantomData,
+};
+use serde::{
+    de::{
+        self,
+        Deserialize,
+        Deserializer,
+        MapAccess,
+        SeqAccess,
+        Visitor,
+    },
+    ser::{
+        Serialize,
+        SerializeStruct,
+        Serializer,
+    },
+};
+use crate::{
+    FixedVec,
+    prelude::*,
+};
+
+const FIELDS: &[&str] = &["slice_length", "items"];
+
+/// Serializes the items as a sequence of value and tail pairs.
+struct Items<'a, T, S>(&'a FixedVec<T, S>);
+
+impl<T: Serialize, S: Serialize> Serialize for Items<'_, T, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_seq(self.0.iter().map(|handle| (&handle.value, &handle.tail)))
+    }
+}
+
+/// Serialized as a struct of the tail length and a sequence of the items,
+/// where each item is a pair of the value and a sequence of the tail.
+impl<T: Serialize, S: Serialize> Serialize for FixedVec<T, S> {
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut state = serializer.serialize_struct("FixedVec", FIELDS.len())?;
+        state.serialize_field(FIELDS[0], &self.slice_length())?;
+        state.serialize_field(FIELDS[1], &Items(self))?;
+        state.end()
+    }
+}
+
+enum Field {
+    SliceLength,
+    Items,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("`slice_length` or `items`")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                match value {
+                    "slice_length" => Ok(Field::SliceLength),
+                    "items" => Ok(Field::Items),
+                    _ => Err(de::Error::unknown_field(value, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+type Nested<T, S> = std::vec::Vec<(T, Box<[S]>)>;
+
+/// Builds the [`FixedVec`], checking every tail against the tail length.
+fn build<T, S, E: de::Error>(slice_length: usize, items: Nested<T, S>) -> Result<FixedVec<T, S>, E> {
+    if items.is_empty() {
+        return Ok(FixedVec::new(slice_length));
+    }
+    let vec = FixedVec::try_from(items).map_err(de::Error::custom)?;
+    if vec.slice_length() != slice_length {
+        return Err(de::Error::invalid_length(vec.slice_length(), &"a tail of the slice length"));
+    }
+    Ok(vec)
+}
+
+struct FixedVecVisitor<T, S>(PhantomData<fn() -> FixedVec<T, S>>);
+
+impl<'de, T: Deserialize<'de>, S: Deserialize<'de>> Visitor<'de> for FixedVecVisitor<T, S> {
+    type Value = FixedVec<T, S>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("struct FixedVec")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let slice_length = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let items = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        build(slice_length, items)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut slice_length = None;
+        let mut items = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::SliceLength => {
+                    if slice_length.is_some() {
+                        return Err(de::Error::duplicate_field(FIELDS[0]));
+                    }
+                    slice_length = Some(map.next_value()?);
+                },
+                Field::Items => {
+                    if items.is_some() {
+                        return Err(de::Error::duplicate_field(FIELDS[1]));
+                    }
+                    items = Some(map.next_value()?);
+                },
+            }
+        }
+        build(
+            slice_length.ok_or_else(|| de::Error::missing_field(FIELDS[0]))?,
+            items.ok_or_else(|| de::Error::missing_field(FIELDS[1]))?,
+        )
+    }
+}
+
+impl<'de, T: Deserialize<'de>, S: Deserialize<'de>> Deserialize<'de> for FixedVec<T, S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("FixedVec", FIELDS, FixedVecVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json() {
+        let mut vec = FixedVec::<String, u8>::new(2);
+        vec.push("a".to_string(), [1, 2]);
+        vec.push("b".to_string(), [3, 4]);
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, r#"{"slice_length":2,"items":[["a",[1,2]],["b",[3,4]]]}"#);
+        assert_eq!(serde_json::from_str::<FixedVec<String, u8>>(&json).unwrap(), vec);
+
+        let empty = serde_json::from_str::<FixedVec<String, u8>>(r#"{"items":[],"slice_length":3}"#).unwrap();
+        assert_eq!(empty.slice_length(), 3);
+        assert!(serde_json::from_str::<FixedVec<String, u8>>(r#"{"slice_length":1,"items":[["a",[1,2]]]}"#).is_err());
+        assert!(serde_json::from_str::<FixedVec<String, u8>>(r#"{"slice_length":2,"items":[["a",[1,2]],["b",[3]]]}"#).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    fmt::Debug,
     marker::PhantomData,
     num::NonZeroUsize,
     ptr,
@@ -38,6 +39,31 @@ pub fn new_capacity_at_least_double(old_capacity: usize) -> Option<NonZeroUsize>
     }
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn index_out_of_bounds(ix: usize, len: usize, slice: impl Debug) -> ! {
+    panic!(
+        "index out of bounds: the len is {} (slice length {:?}) but the index is {}",
+        len,
+        slice,
+        ix,
+    )
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub fn cell_out_of_bounds(ix: usize, column: usize, len: usize, slice: usize) -> ! {
+    panic!(
+        "index out of bounds: the len is {} (slice length {}) but the index is ({}, {})",
+        len,
+        slice,
+        ix,
+        column,
+    )
+}
+
 pub trait PtrCopy {
     unsafe fn copy(src: *const u8, dst: *mut u8, count: usize);
 }
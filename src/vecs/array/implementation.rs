@@ -0,0 +1,390 @@
+use std::{
+    mem::ManuallyDrop,
+    num::NonZeroUsize,
+    ops::{
+        Deref,
+        DerefMut,
+        RangeBounds,
+    },
+};
+use crate::{
+    FixedVec,
+    FixedVecIter,
+    FixedVecIterMut,
+    FixedView,
+    FixedViewMut,
+    Handle,
+    util::DefaultIter,
+};
+use super::*;
+
+/// A [`FixedVec`] over some storage. When the items are inline, the
+/// [`FixedVec`] borrows the buffer and writes back the length when dropped.
+/// It must never need to grow while borrowing the buffer.
+pub(crate) enum Fixed<'a, T, S> {
+    Inline {
+        vec: ManuallyDrop<FixedVec<T, S>>,
+        length: &'a mut usize,
+    },
+    Heap(&'a mut FixedVec<T, S>),
+}
+
+impl<T, S> Deref for Fixed<'_, T, S> {
+    type Target = FixedVec<T, S>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Fixed::Inline { vec, .. } => vec,
+            Fixed::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<T, S> DerefMut for Fixed<'_, T, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Fixed::Inline { vec, .. } => vec,
+            Fixed::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<T, S> Drop for Fixed<'_, T, S> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if let Fixed::Inline { vec, length } = self {
+            **length = vec.len();
+        }
+    }
+}
+
+impl<T, S, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Creates a new [`Vec`] that can contain items where the tail length is
+    /// as provided, computing how many items fit in the buffer. Any number of
+    /// zero-sized items fit.
+    pub fn new(slice_length: usize) -> Self {
+        let size = Handle::<T, [S]>::size_slice(slice_length);
+
+        Vec {
+            buffer: Inline::new(),
+            length: 0,
+            slice: slice_length,
+            capacity: BYTES.checked_div(size).unwrap_or(usize::MAX),
+        }
+    }
+
+    /// Returns the maximum number of items that fit.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.slice
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Checks if there is no space left for another item.
+    #[inline(always)]
+    pub fn is_full(&self) -> bool {
+        self.length == self.capacity
+    }
+
+    pub(crate) fn fixed(&mut self) -> Fixed<'_, T, S> {
+        let ptr = NonZeroUsize::new(self.capacity)
+            .map(|capacity| (self.buffer.ptr(), capacity));
+        Fixed::Inline {
+            // The vec is never dropped, and never grows
+            vec: ManuallyDrop::new(unsafe {
+                FixedVec::from_raw_parts(ptr, self.length, self.slice)
+            }),
+            length: &mut self.length,
+        }
+    }
+
+    /// Adds an item using an iterator containing at least enough values to
+    /// populate the DST's slice, or gives back the value and the iterator if
+    /// there is no space left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count.
+    pub fn try_push<I: IntoIterator<Item=S>>(&mut self, value: T, slice: I) -> Result<&mut Handle<T, [S]>, (T, I)> {
+        if self.is_full() {
+            return Err((value, slice));
+        }
+        self.fixed().push(value, slice);
+        let ix = self.length - 1;
+        Ok(unsafe { self.get_unchecked_mut(ix) })
+    }
+
+    /// Inserts an item using an iterator containing at least enough values to
+    /// populate the DST's slice, or gives back the value and the iterator if
+    /// there is no space left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count. Panics if any
+    /// index lower than the one provided has no item.
+    pub fn try_insert<I: IntoIterator<Item=S>>(&mut self, ix: usize, value: T, slice: I) -> Result<&mut Handle<T, [S]>, (T, I)> {
+        if ix > self.length {
+            panic!("Out of bounds insert");
+        }
+        if self.is_full() {
+            return Err((value, slice));
+        }
+        self.fixed().insert(ix, value, slice);
+        Ok(unsafe { self.get_unchecked_mut(ix) })
+    }
+
+    /// Adds an item using an iterator containing at least enough values to
+    /// populate the DST's slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count. Panics if there
+    /// is no space left.
+    #[inline(always)]
+    pub fn push(&mut self, value: T, slice: impl IntoIterator<Item=S>) {
+        if self.try_push(value, slice).is_err() {
+            panic!("No space to insert");
+        }
+    }
+
+    /// Inserts an item using an iterator containing at least enough values to
+    /// populate the DST's slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count. Panics if any
+    /// index lower than the one provided has no item. Panics if there is no
+    /// space left.
+    #[inline(always)]
+    pub fn insert(&mut self, ix: usize, value: T, slice: impl IntoIterator<Item=S>) {
+        if self.try_insert(ix, value, slice).is_err() {
+            panic!("No space to insert");
+        }
+    }
+
+    /// Removes the last inserted element as if it was immediately dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    #[inline(always)]
+    pub fn pop(&mut self) {
+        self.fixed().pop()
+    }
+
+    /// Removes the last inserted element as if the slice part was immediately
+    /// dropped, but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    #[inline(always)]
+    pub fn pop_value(&mut self) -> T {
+        self.fixed().pop_value()
+    }
+
+    /// Returns a reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&Handle<T, [S]>> {
+        self.as_view().get(ix)
+    }
+
+    /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, [S]> {
+        self.as_view().get_unchecked(ix)
+    }
+
+    /// Returns a mutable reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut Handle<T, [S]>> {
+        if ix >= self.length {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked_mut(ix) })
+        }
+    }
+
+    /// Returns a mutable reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, [S]> {
+        &mut *get_ix!(T S, self.buffer.ptr(), self.slice, ix,)
+    }
+
+    /// Swaps the two items in place, moving the entire value and tail of
+    /// each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at either index.
+    #[inline(always)]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.fixed().swap(a, b)
+    }
+
+    /// Returns an iterator that provides references.
+    #[inline(always)]
+    pub fn iter(&self) -> FixedVecIter<'_, T, S> {
+        self.as_view().into_iter()
+    }
+
+    /// Returns an iterator that provides mutable references.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> FixedVecIterMut<'_, T, S> {
+        self.as_view_mut().into_iter()
+    }
+
+    /// Returns a view of all items.
+    #[inline(always)]
+    pub fn as_view(&self) -> FixedView<'_, T, S> {
+        FixedView {
+            ptr: self.buffer.ptr_const(),
+            length: self.length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns a mutable view of all items.
+    #[inline(always)]
+    pub fn as_view_mut(&mut self) -> FixedViewMut<'_, T, S> {
+        FixedViewMut {
+            ptr: self.buffer.ptr(),
+            length: self.length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns a view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn view(&self, range: impl RangeBounds<usize>) -> FixedView<'_, T, S> {
+        self.as_view().view(range)
+    }
+
+    /// Returns a mutable view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    pub fn view_mut(&mut self, range: impl RangeBounds<usize>) -> FixedViewMut<'_, T, S> {
+        let FixedView { ptr, length, slice, .. } = self.view(range);
+        FixedViewMut {
+            ptr,
+            length,
+            slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Items the item at the index, shifting any later items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove(&mut self, ix: usize) {
+        self.fixed().remove(ix)
+    }
+
+    /// Removes the range of items, shifting any later items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn remove_range(&mut self, range: impl RangeBounds<usize>) {
+        self.fixed().remove_range(range)
+    }
+
+    /// Removes the item at the index, replacing it with the last item.
+    /// This avoids excessive copies, but by reordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove_replace(&mut self, ix: usize) {
+        self.fixed().remove_replace(ix)
+    }
+
+    /// Removes the range of items, replacing them with items from the end.
+    /// This avoids excessive copies, but by reordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn remove_range_replace(&mut self, range: impl RangeBounds<usize>) {
+        self.fixed().remove_range_replace(range)
+    }
+
+    /// Removes the item at index as if the slice part was immediately dropped,
+    /// but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove_value(&mut self, ix: usize) -> T {
+        self.fixed().remove_value(ix)
+    }
+}
+
+impl<T, S: Default, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Appends at the end using default to populate the slice, or gives back
+    /// the value if there is no space left.
+    #[inline(always)]
+    pub fn try_push_default_slice(&mut self, value: T) -> Result<&mut Handle<T, [S]>, T> {
+        self.try_push(value, DefaultIter::default())
+            .map_err(|(value, _)| value)
+    }
+}
+
+impl<T: Default, S: Default, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Appends at the end using default to populate the value and slice, or
+    /// returns `None` if there is no space left.
+    #[inline(always)]
+    pub fn try_push_default(&mut self) -> Option<&mut Handle<T, [S]>> {
+        if self.is_full() {
+            None
+        } else {
+            self.try_push_default_slice(T::default()).ok()
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::alloc::Inline;
+
+mod traits;
+mod implementation;
+
+pub(crate) use implementation::Fixed;
+
+/// A [`FixedVec`](crate::FixedVec) with a fixed capacity, storing its items
+/// inline in a buffer of `BYTES` bytes. It never uses an allocator; instead,
+/// [`try_push`](Vec::try_push) and [`try_insert`](Vec::try_insert) give back
+/// the item when there is no space left.
+///
+/// The capacity is the number of items that fit in the buffer, which depends
+/// on the tail length, and is computed when the [`Vec`] is created.
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::ArrayFixedVec;
+///
+/// let mut vec = ArrayFixedVec::<u32, u8, 16>::new(4);
+/// assert_eq!(vec.capacity(), 2);
+///
+/// assert!(vec.try_push(1, [1, 2, 3, 4]).is_ok());
+/// assert!(vec.try_push(2, [5, 6, 7, 8]).is_ok());
+/// let (value, _) = vec.try_push(3, [9, 9, 9, 9]).unwrap_err();
+/// assert_eq!(value, 3);
+/// assert_eq!(vec[1].tail, [5, 6, 7, 8]);
+/// ```
+pub struct Vec<T, S, const BYTES: usize> {
+    pub(crate) buffer: Inline<T, S, BYTES>,
+    pub(crate) length: usize,
+    pub(crate) slice: usize,
+    pub(crate) capacity: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn full() {
+        let mut vec = Vec::<u8, u16, 24>::new(2);
+        assert_eq!(vec.capacity(), 4);
+        for ix in 0..4 {
+            vec.push(ix, [ix as u16; 2]);
+        }
+        assert!(vec.is_full());
+        let (value, slice) = vec.try_insert(0, 9, [9, 9]).unwrap_err();
+        assert_eq!((value, slice), (9, [9, 9]));
+
+        vec.remove(0);
+        vec.try_insert(0, 9, [9, 9]).unwrap();
+        assert_eq!(vec[0].value, 9);
+        assert_eq!(vec[(1, 1)], 1);
+        assert_eq!(vec.pop_value(), 3);
+        assert_eq!(vec.len(), 3);
+    }
+
+    #[test]
+    fn ordering() {
+        let mut vec = Vec::<u8, u16, 24>::new(2);
+        vec.push(0, [1, 2]);
+        let mut other = vec.clone();
+        assert_eq!(vec.cmp(&other), std::cmp::Ordering::Equal);
+        other.push(0, [0, 0]);
+        assert!(vec < other);
+        vec[(0, 1)] = 3;
+        assert!(vec > other);
+    }
+
+    #[test]
+    #[should_panic(expected = "No space to insert")]
+    fn overflow() {
+        let mut vec = Vec::<u8, u8, 4>::new(3);
+        vec.push(0, [0; 3]);
+        vec.push(1, [1; 3]);
+    }
+
+    #[test]
+    fn drops() {
+        let counter = Rc::new(());
+        let mut vec = Vec::<Rc<()>, Rc<()>, 64>::new(1);
+        vec.push(counter.clone(), [counter.clone()]);
+        vec.push(counter.clone(), [counter.clone()]);
+        assert!(vec.try_push(counter.clone(), [counter.clone()]).is_ok());
+        assert_eq!(Rc::strong_count(&counter), 7);
+        vec.pop();
+        assert_eq!(Rc::strong_count(&counter), 5);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = Vec::<(), u32, 0>::new(0);
+        assert_eq!(vec.capacity(), usize::MAX);
+        for _ in 0..100 {
+            vec.push((), []);
+        }
+        vec.remove_range(..50);
+        assert_eq!(vec.len(), 50);
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    ops::{
+        Index,
+        IndexMut,
+    },
+    ptr::read,
+};
+use crate::{
+    FixedVec,
+    FixedVecIter,
+    FixedVecIterMut,
+    Handle,
+    prelude::*,
+    util::{
+        cell_out_of_bounds,
+        index_out_of_bounds,
+    },
+};
+use super::*;
+
+impl<
+    TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR,
+    const BL: usize, const BR: usize,
+> PartialEq<Vec<TR, SR, BR>> for Vec<TL, SL, BL>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Vec<TR, SR, BR>) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
+impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR, const B: usize> PartialEq<FixedVec<TR, SR>> for Vec<TL, SL, B> {
+    #[inline(always)]
+    fn eq(&self, other: &FixedVec<TR, SR>) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
+impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR, const B: usize> PartialEq<Vec<TR, SR, B>> for FixedVec<TL, SL> {
+    #[inline(always)]
+    fn eq(&self, other: &Vec<TR, SR, B>) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
+impl<T: Eq, S: Eq, const B: usize> Eq for Vec<T, S, B> {}
+
+impl<T: PartialOrd, S: PartialOrd, const B: usize> PartialOrd for Vec<T, S, B> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_view().partial_cmp(&other.as_view())
+    }
+}
+
+impl<T: Ord, S: Ord, const B: usize> Ord for Vec<T, S, B> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_view().cmp(&other.as_view())
+    }
+}
+
+impl<T: Hash, S: Hash, const B: usize> Hash for Vec<T, S, B> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().hash(state)
+    }
+}
+
+impl<T: Debug, S: Debug, const B: usize> Debug for Vec<T, S, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_struct("Vec")
+            .field("values", &self.iter())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<T: Copy, S: Copy, const B: usize> Clone for Vec<T, S, B> {
+    fn clone(&self) -> Self {
+        Vec {
+            // All of the items are Copy
+            buffer: unsafe { read(&self.buffer) },
+            length: self.length,
+            slice: self.slice,
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<'a, T, S, const B: usize> IntoIterator for &'a Vec<T, S, B> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = FixedVecIter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S, const B: usize> IntoIterator for &'a mut Vec<T, S, B> {
+    type Item = &'a mut Handle<T, [S]>;
+    type IntoIter = FixedVecIterMut<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, S, const B: usize> Index<usize> for Vec<T, S, B> {
+    type Output = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn index(&self, ix: usize) -> &Self::Output {
+        match self.get(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S, const B: usize> IndexMut<usize> for Vec<T, S, B> {
+    #[inline(always)]
+    fn index_mut(&mut self, ix: usize) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, length, slice),
+        }
+    }
+}
+
+impl<T, S, const B: usize> Index<(usize, usize)> for Vec<T, S, B> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, (ix, column): (usize, usize)) -> &Self::Output {
+        match self.get(ix).and_then(|handle| handle.tail.get(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S, const B: usize> IndexMut<(usize, usize)> for Vec<T, S, B> {
+    #[inline(always)]
+    fn index_mut(&mut self, (ix, column): (usize, usize)) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix).and_then(|handle| handle.tail.get_mut(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, length, slice),
+        }
+    }
+}
+
+impl<T, S, const B: usize> Drop for Vec<T, S, B> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.fixed().remove_range(..)
+    }
+}
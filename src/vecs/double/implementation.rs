@@ -0,0 +1,299 @@
+use std::{
+    alloc::Layout,
+    num::NonZeroUsize,
+    ptr::{
+        drop_in_place,
+        read,
+        slice_from_raw_parts_mut,
+        write,
+    },
+};
+use crate::alloc::{
+    alloc_capacity,
+    array_layout,
+    empty_alloc,
+    reserve_alloc,
+    reserve_exact_alloc,
+};
+use super::*;
+
+/// Writes exactly the specified number of values from the iterator.
+///
+/// # Panics
+///
+/// When the iterator does not have enough values.
+fn fill<S>(ptr: *mut S, length: usize, values: impl IntoIterator<Item=S>) {
+    let mut values = values.into_iter();
+    for offset in 0..length {
+        match values.next() {
+            Some(value) => unsafe { write(ptr.add(offset), value) },
+            None => panic!("Not enough values to populate handle"),
+        }
+    }
+}
+
+impl Row {
+    fn new<T, A, B>(a_length: usize, b_length: usize) -> Self {
+        let (layout, a_offset) = Layout::new::<T>()
+            .extend(Layout::array::<A>(a_length).expect("Overflow"))
+            .expect("Overflow");
+        let (layout, b_offset) = layout
+            .extend(Layout::array::<B>(b_length).expect("Overflow"))
+            .expect("Overflow");
+        let layout = layout.pad_to_align();
+        Row {
+            size: layout.size(),
+            align: layout.align(),
+            a_offset,
+            b_offset,
+        }
+    }
+
+    /// Returns the layout of a single item.
+    #[inline(always)]
+    pub(super) fn item(&self) -> Layout {
+        Layout::from_size_align(self.size, self.align).expect("Bad Layout")
+    }
+
+    pub(super) fn layout(&self, count: NonZeroUsize) -> Layout {
+        array_layout(self.item(), count)
+    }
+}
+
+impl<T, A, B> Vec<T, A, B> {
+    /// Creates a new [`Vec`] that can contain items where the lengths of the
+    /// tails are as provided. Will not allocate until an item is inserted or
+    /// capacity reserved, and never allocates for zero-sized items.
+    pub fn new(a_length: usize, b_length: usize) -> Self {
+        let row = Row::new::<T, A, B>(a_length, b_length);
+
+        Vec {
+            ptr: empty_alloc(row.item()),
+            length: 0,
+            a_length,
+            b_length,
+            row,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns the maximum number of items before a reallocation is needed.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        alloc_capacity(&self.ptr)
+    }
+
+    /// Returns the length of the first tail for any/all items.
+    #[inline(always)]
+    pub fn a_length(&self) -> usize {
+        self.a_length
+    }
+
+    /// Returns the length of the second tail for any/all items.
+    #[inline(always)]
+    pub fn b_length(&self) -> usize {
+        self.b_length
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Will insure it has enough space for the specified number of items,
+    /// growing according to an internal criteria.
+    pub fn reserve(&mut self, additional: usize) {
+        reserve_alloc(&mut self.ptr, self.row.item(), self.length, additional)
+    }
+
+    /// Will allocate exactly enough memory to insure it has enough space for
+    /// the specified number of elements, or do nothing if it has already
+    /// allocated enough.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        reserve_exact_alloc(&mut self.ptr, self.row.item(), self.length, additional)
+    }
+
+    /// Returns pointers to the value and the start of both tails.
+    ///
+    /// # Safety
+    ///
+    /// There must be an allocation with space for the index.
+    #[inline(always)]
+    unsafe fn item(&self, ix: usize) -> (*mut T, *mut A, *mut B) {
+        let ptr = match self.ptr {
+            Some((ptr, _)) => ptr.as_ptr().add(ix * self.row.size),
+            None => std::hint::unreachable_unchecked(),
+        };
+        (
+            ptr as *mut T,
+            ptr.add(self.row.a_offset) as *mut A,
+            ptr.add(self.row.b_offset) as *mut B,
+        )
+    }
+
+    /// Drops the item in place.
+    ///
+    /// # Safety
+    ///
+    /// The index must be populated, and must not be used again until
+    /// repopulated.
+    unsafe fn drop_item(&self, ix: usize) {
+        let (value, a, b) = self.item(ix);
+        drop_in_place(value);
+        drop_in_place(slice_from_raw_parts_mut(a, self.a_length));
+        drop_in_place(slice_from_raw_parts_mut(b, self.b_length));
+    }
+
+    /// Adds an item using iterators containing at least enough values to
+    /// populate both tails.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either iterator has insufficient element count.
+    pub fn push(&mut self, value: T, a: impl IntoIterator<Item=A>, b: impl IntoIterator<Item=B>) {
+        self.reserve(1);
+        let (value_ptr, a_ptr, b_ptr) = unsafe { self.item(self.length) };
+        unsafe { write(value_ptr, value) };
+        fill(a_ptr, self.a_length, a);
+        fill(b_ptr, self.b_length, b);
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Removes the last inserted element as if it was immediately dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop(&mut self) {
+        if self.length == 0 {
+            panic!("No value to remove");
+        }
+        self.length -= 1;
+        unsafe { self.drop_item(self.length) }
+    }
+
+    /// Removes the last inserted element as if both tails were immediately
+    /// dropped, but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop_value(&mut self) -> T {
+        if self.length == 0 {
+            panic!("No value to remove");
+        }
+        self.length -= 1;
+        unsafe {
+            let (value, a, b) = self.item(self.length);
+            drop_in_place(slice_from_raw_parts_mut(a, self.a_length));
+            drop_in_place(slice_from_raw_parts_mut(b, self.b_length));
+            read(value)
+        }
+    }
+
+    /// Removes the item at the index as if it was immediately dropped,
+    /// shifting all items after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index has no item.
+    pub fn remove(&mut self, ix: usize) {
+        if ix >= self.length {
+            panic!("Out of bounds");
+        }
+        let old_len = self.length;
+        // Pre-poop the pants
+        self.length = ix;
+        unsafe {
+            self.drop_item(ix);
+            let (dst, _, _) = self.item(ix);
+            let (src, _, _) = self.item(ix + 1);
+            (src as *const u8).copy_to(dst as *mut u8, (old_len - ix - 1) * self.row.size);
+        }
+        // Clean the pants
+        self.length = old_len - 1;
+    }
+
+    /// Returns the item at the index, if it exists.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<(&T, &[A], &[B])> {
+        if ix < self.length {
+            Some(unsafe { self.get_unchecked(ix) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at the index without checking bounds.
+    ///
+    /// # Safety
+    ///
+    /// The index must have an item.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> (&T, &[A], &[B]) {
+        let (value, a, b) = self.item(ix);
+        (
+            &*value,
+            &*slice_from_raw_parts_mut(a, self.a_length),
+            &*slice_from_raw_parts_mut(b, self.b_length),
+        )
+    }
+
+    /// Returns the item at the index mutably, if it exists.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<(&mut T, &mut [A], &mut [B])> {
+        if ix < self.length {
+            Some(unsafe { self.get_unchecked_mut(ix) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at the index mutably without checking bounds.
+    ///
+    /// # Safety
+    ///
+    /// The index must have an item.
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> (&mut T, &mut [A], &mut [B]) {
+        let (value, a, b) = self.item(ix);
+        (
+            &mut *value,
+            &mut *slice_from_raw_parts_mut(a, self.a_length),
+            &mut *slice_from_raw_parts_mut(b, self.b_length),
+        )
+    }
+
+    /// Returns an iterator over the items.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, A, B> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over the items, allowing modification.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A, B> {
+        self.into_iter()
+    }
+}
+
+impl<T: Default, A: Default, B: Default> Vec<T, A, B> {
+    /// Appends at the end using default to populate the value and tails.
+    #[inline(always)]
+    pub fn push_default(&mut self) {
+        self.push(
+            T::default(),
+            std::iter::repeat_with(A::default),
+            std::iter::repeat_with(B::default),
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    marker::PhantomData,
+    ops::Range,
+};
+use crate::alloc::Alloc;
+
+mod traits;
+mod implementation;
+
+/// A [`FixedVec`](crate::FixedVec) where every item has two tails of
+/// independent element types, such as indices paired with weights. Each tail
+/// has its own length, fixed for all items, and both are stored inline after
+/// the value in the same allocation, with whatever padding the alignment of
+/// the second tail requires.
+///
+/// The language has no type for a value followed by two unsized slices, so
+/// items are accessed as a tuple of the value and both tails.
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::FixedVec2;
+///
+/// let mut vec = FixedVec2::<&str, u32, f64>::new(3, 2);
+/// vec.push("first", [1, 2, 3], [0.5, 0.25]);
+/// let (value, indices, weights) = vec.get(0).unwrap();
+/// assert_eq!(*value, "first");
+/// assert_eq!(indices, [1, 2, 3]);
+/// assert_eq!(weights, [0.5, 0.25]);
+/// ```
+pub struct Vec<T, A, B> {
+    ptr: Alloc,
+    length: usize,
+    a_length: usize,
+    b_length: usize,
+    row: Row,
+    _phantom: PhantomData<(T, A, B)>,
+}
+
+/// The layout of one item, where the value is always first.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct Row {
+    size: usize,
+    align: usize,
+    a_offset: usize,
+    b_offset: usize,
+}
+
+/// Iterator over references of the items of a [`Vec`].
+pub struct Iter<'a, T, A, B> {
+    iter: Range<usize>,
+    ptr: &'a Vec<T, A, B>,
+}
+
+/// Iterator over mutable references of the items of a [`Vec`].
+pub struct IterMut<'a, T, A, B> {
+    iter: Range<usize>,
+    ptr: &'a mut Vec<T, A, B>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let vec = Vec::<u8, u8, u64>::new(3, 2);
+        assert_eq!(vec.row, Row {
+            size: 24,
+            align: 8,
+            a_offset: 1,
+            b_offset: 8,
+        });
+        let vec = Vec::<u16, u64, u8>::new(1, 3);
+        assert_eq!(vec.row, Row {
+            size: 24,
+            align: 8,
+            a_offset: 8,
+            b_offset: 16,
+        });
+    }
+
+    #[test]
+    fn access() {
+        let mut vec = Vec::<u8, u16, u64>::new(2, 3);
+        for ix in 0..10 {
+            vec.push(ix, [ix as u16; 2], [ix as u64 * 2; 3]);
+        }
+        let (value, a, b) = vec.get_mut(4).unwrap();
+        *value = 40;
+        a[1] = 41;
+        b[2] = 42;
+        assert_eq!(vec.get(4), Some((&40, &[4, 41][..], &[8, 8, 42][..])));
+        vec.remove(0);
+        assert_eq!(vec.len(), 9);
+        assert_eq!(vec.pop_value(), 9);
+        assert_eq!(vec.iter().map(|(value, _, _)| *value).sum::<u8>(), 72);
+        assert_eq!(vec.clone(), vec);
+    }
+
+    #[test]
+    fn drops() {
+        let counter = Rc::new(());
+        let mut vec = Vec::<Rc<()>, Rc<()>, Rc<()>>::new(1, 2);
+        for _ in 0..5 {
+            vec.push(counter.clone(), std::iter::repeat(counter.clone()), std::iter::repeat(counter.clone()));
+        }
+        assert_eq!(Rc::strong_count(&counter), 21);
+        vec.pop();
+        vec.remove(1);
+        assert_eq!(Rc::strong_count(&counter), 13);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = Vec::<(), (), ()>::new(2, 0);
+        for _ in 0..10 {
+            vec.push((), [(), ()], []);
+        }
+        assert_eq!(vec.capacity(), usize::MAX);
+        assert_eq!(vec.get(9).unwrap().1.len(), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    alloc::dealloc_layout,
+    prelude::*,
+};
+use super::*;
+
+unsafe impl<T: Send, A: Send, B: Send> Send for Vec<T, A, B> {}
+unsafe impl<T: Sync, A: Sync, B: Sync> Sync for Vec<T, A, B> {}
+
+impl<T: PartialEq, A: PartialEq, B: PartialEq> PartialEq for Vec<T, A, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Eq, B: Eq> Eq for Vec<T, A, B> {}
+
+impl<T: Hash, A: Hash, B: Hash> Hash for Vec<T, A, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: Debug, A: Debug, B: Debug> Debug for Vec<T, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+impl<T: Clone, A: Clone, B: Clone> Clone for Vec<T, A, B> {
+    fn clone(&self) -> Self {
+        let mut new = Self::new(self.a_length, self.b_length);
+        new.reserve_exact(self.length);
+        for (value, a, b) in self {
+            new.push(value.clone(), a.iter().cloned(), b.iter().cloned());
+        }
+        new
+    }
+}
+
+impl<'a, T, A, B> Iterator for Iter<'a, T, A, B> {
+    type Item = (&'a T, &'a [A], &'a [B]);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe { self.ptr.get_unchecked(ix) })
+    }
+}
+
+impl<'a, T, A, B> Iterator for IterMut<'a, T, A, B> {
+    type Item = (&'a mut T, &'a mut [A], &'a mut [B]);
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe {
+                // Cheating the lifetime
+                let (value, a, b) = self.ptr.get_unchecked_mut(ix);
+                (&mut *(value as *mut _), &mut *(a as *mut _), &mut *(b as *mut _))
+            })
+    }
+}
+
+impl<'a, T, A, B> IntoIterator for &'a Vec<T, A, B> {
+    type Item = (&'a T, &'a [A], &'a [B]);
+    type IntoIter = Iter<'a, T, A, B>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            iter: 0..(self.length),
+            ptr: self,
+        }
+    }
+}
+
+impl<'a, T, A, B> IntoIterator for &'a mut Vec<T, A, B> {
+    type Item = (&'a mut T, &'a mut [A], &'a mut [B]);
+    type IntoIter = IterMut<'a, T, A, B>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            iter: 0..(self.length),
+            ptr: self,
+        }
+    }
+}
+
+impl<T, A, B> Drop for Vec<T, A, B> {
+    fn drop(&mut self) {
+        while !self.is_empty() {
+            self.pop()
+        }
+        if let Some((ptr, capacity)) = self.ptr.take() {
+            unsafe { dealloc_layout(ptr, self.row.layout(capacity)) }
+        }
+    }
+}
@@ -1,31 +1,48 @@
 use std::{
-    alloc::{
-        alloc,
-        handle_alloc_error,
-        Layout,
-        realloc,
-    },
+    alloc::Layout,
     collections::Bound,
     hint::unreachable_unchecked,
+    iter::FromIterator,
+    marker::PhantomData,
+    mem::{
+        ManuallyDrop,
+        MaybeUninit,
+    },
     num::NonZeroUsize,
     ops::{
         Range,
         RangeBounds,
     },
     ptr::{
+        copy_nonoverlapping,
         drop_in_place,
         NonNull,
         read,
+        slice_from_raw_parts_mut,
+        swap_nonoverlapping,
+        write,
     },
 };
 use crate::{
+    alloc::{
+        Alloc,
+        alloc_capacity,
+        alloc_layout,
+        dealloc_layout,
+        empty_alloc,
+        grow_alloc,
+        reserve_alloc,
+        reserve_exact_alloc,
+        zero_sized,
+    },
+    error::FlatLengthError,
+    DstTail,
     Handle,
     util::{
-        can_try_alloc,
         CopyFn,
         CopyNonoverlappingFn,
         DefaultIter,
-        new_capacity_at_least_double,
+        index_out_of_bounds,
         PtrCopy,
     },
 };
@@ -46,30 +63,110 @@ fn convert_range(len: &usize, range: impl RangeBounds<usize>) -> Range<usize> {
     start..end
 }
 
+/// Converts the range, checking it against the length.
+#[track_caller]
+pub(super) fn checked_range(len: usize, range: impl RangeBounds<usize>) -> Range<usize> {
+    let range = convert_range(&len, range);
+    if range.start > range.end {
+        panic!("range starts at {} but ends at {}", range.start, range.end);
+    }
+    if range.end > len {
+        panic!("range end index {} out of range for length {}", range.end, len);
+    }
+    range
+}
+
+/// The number of rows and columns in each block of a transpose.
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Visits every cell of a table in square blocks, so that the rows of both
+/// the table and its transpose stay in cache.
+fn for_each_blocked(rows: usize, columns: usize, mut f: impl FnMut(usize, usize)) {
+    for row_block in (0..rows).step_by(TRANSPOSE_BLOCK) {
+        for column_block in (0..columns).step_by(TRANSPOSE_BLOCK) {
+            for row in row_block..rows.min(row_block + TRANSPOSE_BLOCK) {
+                for column in column_block..columns.min(column_block + TRANSPOSE_BLOCK) {
+                    f(row, column);
+                }
+            }
+        }
+    }
+}
+
+/// The items not yet mapped, which are dropped if the mapping panics.
+struct Unmapped<T, S> {
+    ptr: NonNull<u8>,
+    /// Only present when the allocation is not reused.
+    capacity: Option<NonZeroUsize>,
+    start: usize,
+    length: usize,
+    slice: usize,
+    _phantom: PhantomData<Handle<T, [S]>>,
+}
+
+impl<T, S> Drop for Unmapped<T, S> {
+    fn drop(&mut self) {
+        let Unmapped { ptr, capacity, start, length, slice, _phantom } = *self;
+        // Pre-poop the pants
+        self.start = length;
+        for ix in start..length {
+            unsafe { drop_in_place(get_ix!(T S, ptr, slice, ix,)) }
+        }
+        if let Some(capacity) = capacity {
+            unsafe { dealloc_layout(
+                ptr,
+                Handle::<T, [S]>::layout_slice(slice, capacity),
+            ) }
+        }
+    }
+}
+
+/// Returns the number of items in a flat buffer of the length.
+pub(super) fn flat_items(length: usize, slice: usize) -> Result<usize, FlatLengthError> {
+    match length.checked_div(slice) {
+        Some(items) if items * slice == length => Ok(items),
+        None if length == 0 => Ok(0),
+        _ => Err(FlatLengthError {
+            length,
+            slice_length: slice,
+        }),
+    }
+}
+
 impl<T, S> Vec<T, S> {
     /// Creates a new [`Vec`] that can contain items where the tail length is
     /// as provided. Will not allocate until an item is inserted or capacity
-    /// reserved.
+    /// reserved, and never allocates for zero-sized items.
     pub fn new(slice_length: usize) -> Self {
-        let size = Handle::<T, [S]>::size_slice(slice_length);
-        assert_ne!(size, 0, "Zero-sized DST is pointless");
-
         Vec {
-            ptr: None,
+            ptr: empty_alloc(<[S]>::layout::<T>(slice_length)),
             length: 0,
             slice: slice_length,
             _phantom: Default::default(),
         }
     }
 
+    /// Creates a [`Vec`] from an existing allocation and its items.
+    ///
+    /// # Safety
+    ///
+    /// If there is an allocation, it must have the layout of
+    /// [`Handle::layout_slice`] for the capacity, and must contain the
+    /// specified number of items.
+    #[inline(always)]
+    pub(crate) unsafe fn from_raw_parts(ptr: Alloc, length: usize, slice: usize) -> Self {
+        Vec {
+            ptr,
+            length,
+            slice,
+            _phantom: Default::default(),
+        }
+    }
+
     /// Returns the maximum number of items before a reallocation is needed.
     #[inline(always)]
     pub fn capacity(&self) -> usize {
-        if let Some((_, value)) = self.ptr {
-            value.get()
-        } else {
-            0
-        }
+        alloc_capacity(&self.ptr)
     }
 
     /// Returns the length of the tail for any/all items.
@@ -81,60 +178,26 @@ impl<T, S> Vec<T, S> {
     /// Will insure it has enough space for the specified number of items,
     /// growing according to an internal criteria.
     pub fn reserve(&mut self, additional: usize) {
-        let capacity = self.capacity();
-        if capacity - self.length >= additional {
-            return;
-        }
-        self.alloc_grow(new_capacity_at_least_double(if additional <= capacity {
-            // it at-least doubles, which means it will be at-least `additional`
-            capacity
-        } else {
-            // This is the lowest number that is at-least half of the desired capacity.
-            // Thus, at-least double provides at-least the desired capacity.
-            additional
-                .checked_add(self.length)
-                .and_then(|v| v.checked_add(1))
-                .expect("Overflow")
-                / 2
-        }).expect("Overflow"))
+        let item = self.item_layout();
+        reserve_alloc(&mut self.ptr, item, self.length, additional)
     }
 
     /// Will allocate exactly enough memory to insure it has enough space for
     /// the specified number of elements, or do nothing if it has already
     /// allocated enough.
     pub fn reserve_exact(&mut self, additional: usize) {
-        if self.capacity() - self.length >= additional {
-            return;
-        }
-        // X >= Z is always true when Z == 0, which would return
-        // Y + Z > 0 is always true when Z != 0
-        self.alloc_grow(unsafe { NonZeroUsize::new_unchecked(
-            self.length.checked_add(additional).expect("Overflow")
-        )})
+        let item = self.item_layout();
+        reserve_exact_alloc(&mut self.ptr, item, self.length, additional)
     }
 
-    pub(super) fn alloc_grow(&mut self, total: NonZeroUsize) {
-        let layout = Handle::<T, [S]>::layout_slice(self.slice, total);
-        if !can_try_alloc(layout.size()) {
-            panic!("Overflow");
-        }
-
-        let ptr = if let Some((ptr, capacity)) = self.ptr {
-            let old_layout = Handle::<T, [S]>::layout_slice(self.slice, capacity);
-            unsafe {
-                realloc(ptr.as_ptr(), old_layout, layout.size())
-            }
-        } else {
-            unsafe {
-                alloc(layout)
-            }
-        };
+    #[inline(always)]
+    fn item_layout(&self) -> Layout {
+        <[S]>::layout::<T>(self.slice)
+    }
 
-        if let Some(ptr) = NonNull::new(ptr) {
-            self.ptr = Some((ptr, total))
-        } else {
-            handle_alloc_error(layout)
-        }
+    pub(super) fn alloc_grow(&mut self, total: NonZeroUsize) {
+        let item = self.item_layout();
+        grow_alloc(&mut self.ptr, item, total)
     }
 
     fn do_push(&mut self, value: T, slice: impl IntoIterator<Item=S>) -> &mut Handle<T, [S]> {
@@ -200,6 +263,84 @@ impl<T, S> Vec<T, S> {
         self.do_insert(ix, value, slice);
     }
 
+    /// Populates the tail of the item at the index with the closure, then the
+    /// value. The index is not put in the drop.
+    ///
+    /// # Safety
+    ///
+    /// The index must be allocated and must not hold an item.
+    unsafe fn init_with<E>(
+        &mut self,
+        ix: usize,
+        value: T,
+        init: impl FnOnce(&mut TailInit<'_, S>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let handle = get_ix!(T S self ix);
+        let mut tail = TailInit::new(
+            NonNull::new_unchecked(Handle::tail_ptr(handle) as *mut S),
+            self.slice,
+        );
+        init(&mut tail)?;
+        tail.finish();
+        write(Handle::value_ptr(handle), value);
+        Ok(())
+    }
+
+    /// Adds an item, letting the closure write the cells of the tail in any
+    /// order. If the closure returns an error, the written cells and the
+    /// value are dropped and nothing is added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the closure returns successfully without writing every cell.
+    /// If the closure panics, the written cells are dropped.
+    pub fn push_with<E>(
+        &mut self,
+        value: T,
+        init: impl FnOnce(&mut TailInit<'_, S>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        self.reserve(1);
+        unsafe { self.init_with(self.length, value, init)? };
+
+        // This puts it in the drop
+        self.length += 1;
+        Ok(())
+    }
+
+    /// Inserts an item, letting the closure write the cells of the tail in
+    /// any order. If the closure returns an error, the written cells and the
+    /// value are dropped and nothing is inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the closure returns successfully without writing every cell.
+    /// Panics if any index lower than the one provided has no item. If the
+    /// closure panics, the written cells are dropped.
+    pub fn insert_with<E>(
+        &mut self,
+        ix: usize,
+        value: T,
+        init: impl FnOnce(&mut TailInit<'_, S>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        if ix > self.length {
+            panic!("Out of bounds insert");
+        }
+        self.reserve(1);
+        // Constructed after the end, so that nothing moves until it succeeds
+        unsafe { self.init_with(self.length, value, init)? };
+
+        let size = Handle::<T, [S]>::size_slice(self.slice);
+        unsafe {
+            let start = get_ix!(T S self ix) as *mut MaybeUninit<u8>;
+            let bytes = &mut *slice_from_raw_parts_mut(start, (self.length + 1 - ix) * size);
+            bytes.rotate_right(size);
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+        Ok(())
+    }
+
     /// Removes the last inserted element as if it was immediately dropped.
     ///
     /// # Panics
@@ -231,12 +372,7 @@ impl<T, S> Vec<T, S> {
             };
             let layout = Layout::for_value(&*get_ix!(T S, ptr, self.slice, self.length,));
 
-            let target = alloc(layout);
-            let target = if let Some(target) = NonNull::new(target) {
-                target
-            } else {
-                handle_alloc_error(layout);
-            };
+            let target = alloc_layout(layout);
             self.shift_memory::<CopyNonoverlappingFn>(target, 0, ptr, self.length, 1);
             let target = get_ix!(T S, target, self.slice, 0,);
             Box::from_raw(target)
@@ -264,6 +400,12 @@ impl<T, S> Vec<T, S> {
         }
     }
 
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
     /// Checks if there are any items.
     #[inline(always)]
     pub fn is_empty(&self) -> bool {
@@ -281,6 +423,10 @@ impl<T, S> Vec<T, S> {
     }
 
     /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
     #[inline(always)]
     pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, [S]> {
         &*get_ix!(T S self ix)
@@ -297,11 +443,36 @@ impl<T, S> Vec<T, S> {
     }
 
     /// Returns a mutable reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
     #[inline(always)]
     pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, [S]> {
         &mut *get_ix!(T S self ix)
     }
 
+    /// Swaps the two items in place, moving the entire value and tail of
+    /// each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at either index.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.length || b >= self.length {
+            index_out_of_bounds(a.max(b), self.length, self.slice);
+        }
+        if a == b {
+            return;
+        }
+        let size = Handle::<T, [S]>::size_slice(self.slice);
+        unsafe { swap_nonoverlapping(
+            get_ix!(T S self a) as *mut u8,
+            get_ix!(T S self b) as *mut u8,
+            size,
+        ) }
+    }
+
     /// Returns an iterator that provides references.
     #[inline(always)]
     pub fn iter(&self) -> Iter<'_, T, S> {
@@ -314,6 +485,119 @@ impl<T, S> Vec<T, S> {
         self.into_iter()
     }
 
+    /// Returns a view of all items.
+    #[inline(always)]
+    pub fn as_view(&self) -> View<'_, T, S> {
+        View {
+            ptr: self.ptr.map_or(NonNull::dangling(), |(ptr, _)| ptr),
+            length: self.length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns a mutable view of all items.
+    #[inline(always)]
+    pub fn as_view_mut(&mut self) -> ViewMut<'_, T, S> {
+        ViewMut {
+            ptr: self.ptr.map_or(NonNull::dangling(), |(ptr, _)| ptr),
+            length: self.length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns a mutable view of the uninitialized items between the length
+    /// and the capacity, such as to be written before
+    /// [`set_len`](Self::set_len).
+    pub fn spare_capacity_mut(&mut self) -> ViewMut<'_, MaybeUninit<T>, MaybeUninit<S>> {
+        let (ptr, length) = match self.ptr {
+            Some((ptr, capacity)) => unsafe { (
+                NonNull::new_unchecked(ptr.as_ptr().add(self.length * self.item_size())),
+                capacity.get() - self.length,
+            ) },
+            None => (NonNull::dangling(), 0),
+        };
+        ViewMut {
+            ptr,
+            length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns the number of bytes from the start of one item to the next.
+    #[inline(always)]
+    pub fn item_size(&self) -> usize {
+        Handle::<T, [S]>::size_slice(self.slice)
+    }
+
+    /// Returns a pointer to the first item, which is dangling (but aligned)
+    /// if nothing was allocated. Items are [`item_size`](Self::item_size)
+    /// bytes apart.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const Handle<T, [S]> {
+        let (ptr, _) = self.ptr.unwrap_or_else(|| zero_sized::<T, S>(self.slice));
+        unsafe { get_ix!(T S, ptr, self.slice, 0,) }
+    }
+
+    /// Returns a mutable pointer to the first item. See
+    /// [`as_ptr`](Self::as_ptr).
+    #[inline(always)]
+    pub fn as_mut_ptr(&mut self) -> *mut Handle<T, [S]> {
+        self.as_ptr() as *mut Handle<T, [S]>
+    }
+
+    /// Sets the number of items, without dropping or initializing anything.
+    ///
+    /// # Safety
+    ///
+    /// The length must not be greater than the capacity, and every item
+    /// before the length must be initialized.
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, length: usize) {
+        self.length = length;
+    }
+
+    /// Returns an iterator that provides references to the cells of the
+    /// column in each item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is not less than the tail length.
+    #[inline(always)]
+    pub fn column(&self, column: usize) -> Column<'_, T, S> {
+        self.as_view().column(column)
+    }
+
+    /// Returns a view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn view(&self, range: impl RangeBounds<usize>) -> View<'_, T, S> {
+        self.as_view().view(range)
+    }
+
+    /// Returns a mutable view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn view_mut(&mut self, range: impl RangeBounds<usize>) -> ViewMut<'_, T, S> {
+        let View { ptr, length, slice, .. } = self.view(range);
+        ViewMut {
+            ptr,
+            length,
+            slice,
+            _phantom: Default::default(),
+        }
+    }
+
     fn remove_drop_range_impl(&mut self, ix: Range<usize>, mode: RemovalMode) {
         if ix.is_empty() {
             return;
@@ -338,12 +622,15 @@ impl<T, S> Vec<T, S> {
             unsafe { drop_in_place(handle) };
         }
 
+        let tail = old_len - ix.end;
         if mode == RemovalMode::Shift
-            && old_len - ix.end > ix.len()
+            && tail > ix.len()
         {
-            self.shift_memory::<CopyFn>(ptr, ix.start, ptr, ix.end, old_len - ix.end);
+            self.shift_memory::<CopyFn>(ptr, ix.start, ptr, ix.end, tail);
         } else {
-            self.shift_memory::<CopyNonoverlappingFn>(ptr, ix.start, ptr, old_len - ix.len(), ix.len());
+            // When the tail is shorter than the range, it is moved as-is
+            let count = tail.min(ix.len());
+            self.shift_memory::<CopyNonoverlappingFn>(ptr, ix.start, ptr, old_len - count, count);
         }
         // Clean the pants
         self.length = old_len - ix.len();
@@ -433,8 +720,8 @@ impl<T, S> Vec<T, S> {
     ) {
         let size = Handle::<T, [S]>::size_slice(self.slice);
         unsafe { F::copy(
-            src.as_ptr().offset((from * size) as isize),
-            dst.as_ptr().offset((to * size) as isize),
+            src.as_ptr().add(from * size),
+            dst.as_ptr().add(to * size),
             size * length,
         ) };
     }
@@ -469,7 +756,7 @@ impl<T, S> Vec<T, S> {
             // At least 1 space has been reserved
             unsafe { unreachable_unchecked() }
         };
-        let other_ptr = if let Some((ptr, _)) = self.ptr {
+        let other_ptr = if let Some((ptr, _)) = other.ptr {
             ptr
         } else {
             // Other.length == 0 implies "Out of bounds"
@@ -486,6 +773,533 @@ impl<T, S> Vec<T, S> {
         // Clean the pants
         other.length = old_length - range.len();
     }
+
+    /// Moves every item into a new allocation with the provided tail length.
+    /// Each value is moved by this function, while each tail is moved by the
+    /// provided function from the old location to the new one.
+    ///
+    /// # Safety
+    ///
+    /// The function must consume every old cell, by moving or dropping, and
+    /// must initialize every new cell. It may leak on panic, but may not
+    /// leave any cell dropped more than once.
+    unsafe fn relayout(&mut self, slice: usize, mut tail: impl FnMut(*mut S, *mut S)) {
+        let mut new = Vec::new(slice);
+        new.reserve_exact(self.length);
+
+        let length = self.length;
+        // Pre-poop the pants; a panic leaks the remaining items
+        self.length = 0;
+        if let (Some((old_ptr, _)), Some((new_ptr, _))) = (self.ptr, new.ptr) {
+            for ix in 0..length {
+                let src = get_ix!(T S, old_ptr, self.slice, ix,);
+                let dst = get_ix!(T S, new_ptr, slice, ix,);
+                tail(Handle::tail_ptr(src) as *mut S, Handle::tail_ptr(dst) as *mut S);
+                Handle::value_ptr(dst).write(read(Handle::value_ptr(src)));
+                new.length = ix + 1;
+            }
+        }
+        *self = new;
+    }
+
+    /// Removes the cell at the column of every item, dropping it and shifting
+    /// all cells after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is not less than the tail length.
+    pub fn remove_column(&mut self, column: usize) {
+        let slice = self.slice;
+        if column >= slice {
+            panic!("Out of bounds column");
+        }
+        unsafe {
+            self.relayout(slice - 1, |src, dst| {
+                copy_nonoverlapping(src, dst, column);
+                copy_nonoverlapping(src.add(column + 1), dst.add(column), slice - column - 1);
+                drop_in_place(src.add(column));
+            })
+        }
+    }
+
+    /// Swaps the cells of two columns in every item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either column is not less than the tail length.
+    pub fn swap_columns(&mut self, a: usize, b: usize) {
+        if a >= self.slice || b >= self.slice {
+            panic!("Out of bounds column");
+        }
+        for handle in self.iter_mut() {
+            handle.tail.swap(a, b);
+        }
+    }
+
+    /// Moves every item into a [`Vec`] of another type, where each item is
+    /// written by the provided function. Reuses the allocation when the
+    /// items have the same size and alignment.
+    fn map_rows<U, R>(
+        mut self,
+        slice_length: usize,
+        mut f: impl FnMut(T, TailDrain<'_, S>, &mut Vec<U, R>),
+    ) -> Vec<U, R> {
+        let slice = self.slice;
+        let length = self.length;
+        let (ptr, capacity) = match self.ptr.take() {
+            Some(alloc) => alloc,
+            None => return Vec::new(slice_length),
+        };
+        self.length = 0;
+        let in_place = Handle::<U, [R]>::size_slice(slice_length) == Handle::<T, [S]>::size_slice(slice)
+            && Handle::<U, [R]>::alignment_slice(slice_length) == Handle::<T, [S]>::alignment_slice(slice);
+
+        // Declared first, so a panic drops the unmapped items beforehand
+        let mut mapped = if in_place {
+            unsafe { Vec::from_raw_parts(Some((ptr, capacity)), 0, slice_length) }
+        } else {
+            let mut mapped = Vec::new(slice_length);
+            mapped.reserve_exact(length);
+            mapped
+        };
+        let mut unmapped = Unmapped::<T, S> {
+            ptr,
+            capacity: if in_place { None } else { Some(capacity) },
+            start: 0,
+            length,
+            slice,
+            _phantom: PhantomData,
+        };
+        // When reusing the allocation, each item is moved aside before the
+        // mapped item overwrites it
+        let mut scratch = Vec::<T, S>::new(slice);
+        if in_place {
+            scratch.reserve_exact(1);
+        }
+
+        for ix in 0..length {
+            unsafe {
+                let mut item = get_ix!(T S, ptr, slice, ix,);
+                unmapped.start = ix + 1;
+                if let Some((scratch, _)) = scratch.ptr {
+                    scratch.as_ptr().copy_from_nonoverlapping(item as *const u8, Handle::<T, [S]>::size_slice(slice));
+                    item = get_ix!(T S, scratch, slice, 0,);
+                }
+                let value = read(Handle::value_ptr(item));
+                let tail = TailDrain {
+                    iter: 0..slice,
+                    ptr: NonNull::new_unchecked(Handle::tail_ptr(item) as *mut S),
+                    _phantom: PhantomData,
+                };
+                f(value, tail, &mut mapped);
+            }
+        }
+        mapped
+    }
+
+    /// Moves every item into a [`Vec`] with the provided tail length, using
+    /// the function to map each value and tail. The function must return at
+    /// least enough cells for the new tail length. Reuses the allocation when
+    /// [`Handle<U, [R]>`](Handle) has the same size and alignment.
+    ///
+    /// The returned cells can not borrow the [`TailDrain`], so they must be
+    /// taken or collected from it. To map every cell lazily while keeping the
+    /// tail length, use [`map_items`](Self::map_items).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function returns insufficient cells. Any items not yet
+    /// mapped are dropped.
+    pub fn into_map<U, R, I: IntoIterator<Item=R>>(
+        self,
+        slice_length: usize,
+        mut f: impl FnMut(T, TailDrain<'_, S>) -> (U, I),
+    ) -> Vec<U, R> {
+        self.map_rows(slice_length, |value, tail, mapped| {
+            let (value, tail) = f(value, tail);
+            mapped.push(value, tail);
+        })
+    }
+
+    /// Moves every item into a [`Vec`] with each value and each cell mapped
+    /// by the functions, keeping the tail length. Reuses the allocation when
+    /// the items have the same size and alignment.
+    pub fn map_items<U, R>(
+        self,
+        mut value: impl FnMut(T) -> U,
+        mut cell: impl FnMut(S) -> R,
+    ) -> Vec<U, R> {
+        let slice = self.slice;
+        self.map_rows(slice, |item, tail, mapped| mapped.push(value(item), tail.map(&mut cell)))
+    }
+
+    /// Moves every item into a [`Vec`] with each value mapped by the
+    /// function. Reuses the allocation when the items have the same size and
+    /// alignment.
+    pub fn map_values<U>(self, mut f: impl FnMut(T) -> U) -> Vec<U, S> {
+        let slice = self.slice;
+        self.map_rows(slice, |value, tail, mapped| mapped.push(f(value), tail))
+    }
+
+    /// Moves every item into a [`Vec`] with each cell mapped by the function.
+    /// Reuses the allocation when the items have the same size and alignment.
+    pub fn map_cells<R>(self, mut f: impl FnMut(S) -> R) -> Vec<T, R> {
+        let slice = self.slice;
+        self.map_rows(slice, |value, tail, mapped| mapped.push(value, tail.map(&mut f)))
+    }
+
+    /// Moves every value into a [`std::vec::Vec`] and every cell into a
+    /// column-major [`Vec`], where each item is a column of the original.
+    /// The resulting tail length is the original number of items, and the
+    /// resulting number of items is the original tail length.
+    pub fn into_transpose(mut self) -> (std::vec::Vec<T>, Vec<(), S>) {
+        let length = self.length;
+        let slice = self.slice;
+        let mut values = std::vec::Vec::with_capacity(length);
+        let mut transposed = Vec::new(length);
+        transposed.reserve_exact(slice);
+
+        // Pre-poop the pants
+        self.length = 0;
+        if let Some((src, _)) = self.ptr {
+            for ix in 0..length {
+                values.push(unsafe { read(Handle::value_ptr(get_ix!(T S, src, slice, ix,))) });
+            }
+            if let Some((dst, _)) = transposed.ptr {
+                for_each_blocked(length, slice, |row, column| unsafe {
+                    let src = Handle::tail_ptr(get_ix!(T S, src, slice, row,)) as *mut S;
+                    let dst = Handle::tail_ptr(get_ix!(() S, dst, length, column,)) as *mut S;
+                    copy_nonoverlapping(src.add(column), dst.add(row), 1);
+                });
+                transposed.length = slice;
+            }
+        }
+        if length == 0 {
+            // Every transposed item is zero-sized, as it has no cells
+            transposed.length = slice;
+        }
+        (values, transposed)
+    }
+
+    /// Converts into a [`std::vec::Vec`] of each value paired with its tail
+    /// collected into a separate container, such as a `Vec<S>` or a
+    /// `Box<[S]>`.
+    pub fn into_nested<C: FromIterator<S>>(mut self) -> std::vec::Vec<(T, C)> {
+        let length = self.length;
+        let slice = self.slice;
+        // Pre-poop the pants; a panic while collecting leaks the remainder
+        self.length = 0;
+
+        let mut nested = std::vec::Vec::with_capacity(length);
+        for ix in 0..length {
+            unsafe {
+                let handle = get_ix!(T S self ix);
+                let value = read(Handle::value_ptr(handle));
+                let tail = Handle::tail_ptr(handle) as *mut S;
+                let tail = (0..slice)
+                    .map(|column| read(tail.add(column)))
+                    .collect();
+                nested.push((value, tail));
+            }
+        }
+        nested
+    }
+}
+
+impl<T, S: Clone> Vec<T, S> {
+    /// Adds an item with clones of the cells of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not of the tail length. If cloning panics, the
+    /// value is dropped and the cells cloned so far are leaked.
+    pub fn push_from_slice(&mut self, value: T, slice: &[S]) {
+        if slice.len() != self.slice {
+            panic!("Length mismatch");
+        }
+        self.reserve(1);
+        unsafe {
+            let handle = get_ix!(T S self self.length);
+            let tail = Handle::tail_ptr(handle) as *mut S;
+            for (offset, cell) in slice.iter().enumerate() {
+                tail.add(offset).write(cell.clone());
+            }
+            write(Handle::value_ptr(handle), value);
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Inserts a clone of the fill as the cell at the column of every item,
+    /// shifting all cells after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is greater than the tail length.
+    pub fn insert_column(&mut self, column: usize, fill: S) {
+        let slice = self.slice;
+        if column > slice {
+            panic!("Out of bounds column insert");
+        }
+        unsafe {
+            self.relayout(slice + 1, |src, dst| {
+                dst.add(column).write(fill.clone());
+                copy_nonoverlapping(src, dst, column);
+                copy_nonoverlapping(src.add(column), dst.add(column + 1), slice - column);
+            })
+        }
+    }
+
+    /// Changes the tail length of every item, either dropping the cells past
+    /// the new length or filling the new cells with clones of the fill.
+    pub fn resize_slice_length(&mut self, slice_length: usize, fill: S) {
+        let slice = self.slice;
+        if slice_length == slice {
+            return;
+        }
+        unsafe {
+            self.relayout(slice_length, |src, dst| {
+                if slice_length > slice {
+                    for column in slice..slice_length {
+                        dst.add(column).write(fill.clone());
+                    }
+                    copy_nonoverlapping(src, dst, slice);
+                } else {
+                    copy_nonoverlapping(src, dst, slice_length);
+                    drop_in_place(slice_from_raw_parts_mut(
+                        src.add(slice_length),
+                        slice - slice_length,
+                    ));
+                }
+            })
+        }
+    }
+}
+
+impl<T, S: Copy> Vec<T, S> {
+    /// Adds an item with a copy of the slice, copying all cells at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not of the tail length.
+    pub fn push_copy_from_slice(&mut self, value: T, slice: &[S]) {
+        if slice.len() != self.slice {
+            panic!("Length mismatch");
+        }
+        self.reserve(1);
+        unsafe {
+            let handle = get_ix!(T S self self.length);
+            copy_nonoverlapping(slice.as_ptr(), Handle::tail_ptr(handle) as *mut S, slice.len());
+            write(Handle::value_ptr(handle), value);
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+}
+
+impl<T: Clone, S: Clone> Vec<T, S> {
+    /// Appends clones of the items in the range, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds. If cloning panics, the item
+    /// being cloned is leaked.
+    pub fn extend_from_within(&mut self, range: impl RangeBounds<usize>) {
+        let range = checked_range(self.length, range);
+        self.reserve(range.len());
+        for ix in range {
+            unsafe {
+                let src = &*get_ix!(T S self ix);
+                let dst = get_ix!(T S self self.length);
+                let tail = Handle::tail_ptr(dst) as *mut S;
+                for (offset, cell) in src.tail.iter().enumerate() {
+                    tail.add(offset).write(cell.clone());
+                }
+                write(Handle::value_ptr(dst), src.value.clone());
+            }
+
+            // This puts it in the drop
+            self.length += 1;
+        }
+    }
+
+    /// Creates a new [`Vec`] with clones of the selected columns, in the
+    /// order provided. Columns may be repeated or omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any column is not less than the tail length.
+    pub fn project(&self, columns: &[usize]) -> Self {
+        if columns.iter().any(|&column| column >= self.slice) {
+            panic!("Out of bounds column");
+        }
+        let mut new = Vec::new(columns.len());
+        new.reserve_exact(self.length);
+        for handle in self {
+            new.push(
+                handle.value.clone(),
+                columns.iter().map(|&column| handle.tail[column].clone()),
+            );
+        }
+        new
+    }
+
+    /// Copies into a [`std::vec::Vec`] of each value paired with its tail
+    /// collected into a separate container, such as a `Vec<S>` or a
+    /// `Box<[S]>`.
+    pub fn to_nested<C: FromIterator<S>>(&self) -> std::vec::Vec<(T, C)> {
+        self
+            .iter()
+            .map(|handle| (handle.value.clone(), handle.tail.iter().cloned().collect()))
+            .collect()
+    }
+}
+
+impl<T: Copy, S: Copy> Vec<T, S> {
+    /// Appends copies of the items in the range, in order, copying all of
+    /// them at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn extend_copy_from_within(&mut self, range: impl RangeBounds<usize>) {
+        let range = checked_range(self.length, range);
+        if range.is_empty() {
+            return;
+        }
+        self.reserve(range.len());
+        let ptr = if let Some((ptr, _)) = self.ptr {
+            ptr
+        } else {
+            // At least 1 space has been reserved
+            unsafe { unreachable_unchecked() }
+        };
+        self.shift_memory::<CopyNonoverlappingFn>(ptr, self.length, ptr, range.start, range.len());
+        self.length += range.len();
+    }
+}
+
+impl<S> Vec<(), S> {
+    /// Returns all cells of all items as one slice, row after row.
+    #[inline(always)]
+    pub fn as_flat_slice(&self) -> &[S] {
+        let ptr = self.ptr.map_or(NonNull::dangling(), |(ptr, _)| ptr.cast());
+        unsafe { &*slice_from_raw_parts_mut(ptr.as_ptr(), self.length * self.slice) }
+    }
+
+    /// Returns all cells of all items as one mutable slice, row after row.
+    #[inline(always)]
+    pub fn as_flat_slice_mut(&mut self) -> &mut [S] {
+        let ptr = self.ptr.map_or(NonNull::dangling(), |(ptr, _)| ptr.cast());
+        unsafe { &mut *slice_from_raw_parts_mut(ptr.as_ptr(), self.length * self.slice) }
+    }
+
+    /// Converts into a [`std::vec::Vec`] of all cells of all items, row
+    /// after row, reusing the allocation.
+    pub fn into_flat_vec(mut self) -> std::vec::Vec<S> {
+        let length = self.length.checked_mul(self.slice).expect("Overflow");
+        if Handle::<(), [S]>::size_slice(self.slice) == 0 {
+            // Nothing is allocated, and zero-sized cells need no moving
+            self.length = 0;
+            return (0..length)
+                .map(|_| unsafe { read(NonNull::dangling().as_ptr()) })
+                .collect();
+        }
+        match self.ptr.take() {
+            Some((ptr, capacity)) => unsafe { std::vec::Vec::from_raw_parts(
+                ptr.as_ptr() as *mut S,
+                length,
+                capacity.get() * self.slice,
+            ) },
+            None => std::vec::Vec::new(),
+        }
+    }
+
+    /// Converts from a [`std::vec::Vec`] of all cells of all items, row after
+    /// row. Reuses the allocation when its capacity is a multiple of the tail
+    /// length, and otherwise copies into a new one.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the length is not a multiple of the tail length.
+    pub fn from_flat_vec(mut flat: std::vec::Vec<S>, slice_length: usize) -> Result<Self, FlatLengthError> {
+        let length = flat_items(flat.len(), slice_length)?;
+        let mut vec = Vec::new(slice_length);
+        if Handle::<(), [S]>::size_slice(slice_length) == 0 {
+            // Nothing to move, other than zero-sized cells
+            unsafe { flat.set_len(0) };
+            vec.length = length;
+            return Ok(vec);
+        }
+
+        if let Ok(capacity) = flat_items(flat.capacity(), slice_length) {
+            if let Some(capacity) = NonZeroUsize::new(capacity) {
+                let mut flat = ManuallyDrop::new(flat);
+                let ptr = unsafe { NonNull::new_unchecked(flat.as_mut_ptr() as *mut u8) };
+                return Ok(unsafe { Vec::from_raw_parts(Some((ptr, capacity)), length, slice_length) });
+            }
+        }
+
+        vec.reserve_exact(length);
+        if let Some((ptr, _)) = vec.ptr {
+            unsafe {
+                copy_nonoverlapping(flat.as_ptr(), ptr.as_ptr() as *mut S, flat.len());
+                flat.set_len(0);
+            }
+            vec.length = length;
+        }
+        Ok(vec)
+    }
+}
+
+impl<S: Clone> Vec<(), S> {
+    /// Appends clones of a flat buffer of cells as items, row after row.
+    ///
+    /// # Errors
+    ///
+    /// Fails without appending if the buffer length is not a multiple of the
+    /// tail length.
+    pub fn extend_from_flat(&mut self, flat: &[S]) -> Result<(), FlatLengthError> {
+        let length = flat_items(flat.len(), self.slice)?;
+        self.reserve(length);
+        if self.slice != 0 {
+            for row in flat.chunks_exact(self.slice) {
+                self.push((), row.iter().cloned());
+            }
+        } else {
+            for _ in 0..length {
+                self.push((), []);
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the cells into a column-major [`Vec`], where each item is a
+    /// column of this one. The resulting tail length is the number of items,
+    /// and the resulting number of items is the tail length.
+    pub fn transpose(&self) -> Self {
+        let length = self.length;
+        let slice = self.slice;
+        let mut transposed = Vec::new(length);
+        transposed.reserve_exact(slice);
+
+        if let (Some((src, _)), Some((dst, _))) = (self.ptr, transposed.ptr) {
+            for_each_blocked(length, slice, |row, column| unsafe {
+                let src = Handle::tail_ptr(get_ix!(() S, src, slice, row,)) as *mut S;
+                let dst = Handle::tail_ptr(get_ix!(() S, dst, length, column,)) as *mut S;
+                dst.add(row).write((*src.add(column)).clone());
+            });
+            // A panic while cloning leaks the cells cloned so far
+            transposed.length = slice;
+        }
+        if length == 0 {
+            // Every transposed item is zero-sized, as it has no cells
+            transposed.length = slice;
+        }
+        transposed
+    }
 }
 
 impl<T, S: Default> Vec<T, S> {
@@ -0,0 +1,234 @@
+use std::{
+    marker::PhantomData,
+    mem::{
+        self,
+        MaybeUninit,
+    },
+    ops::RangeBounds,
+    ptr::{
+        drop_in_place,
+        slice_from_raw_parts_mut,
+        NonNull,
+    },
+};
+use crate::prelude::*;
+use super::implementation::checked_range;
+
+/// The number of words of the bitmap kept inline, covering the cells of
+/// most tails without allocating.
+const INLINE_WORDS: usize = 4;
+
+/// The cells written so far, one bit per cell.
+enum Bitmap {
+    Inline([u64; INLINE_WORDS]),
+    Heap(std::vec::Vec<u64>),
+}
+
+impl Bitmap {
+    fn new(length: usize) -> Self {
+        let words = length.div_ceil(64);
+        if words <= INLINE_WORDS {
+            Bitmap::Inline([0; INLINE_WORDS])
+        } else {
+            Bitmap::Heap(vec![0; words])
+        }
+    }
+
+    #[inline(always)]
+    fn words(&self) -> &[u64] {
+        match self {
+            Bitmap::Inline(words) => words,
+            Bitmap::Heap(words) => words,
+        }
+    }
+
+    #[inline(always)]
+    fn words_mut(&mut self) -> &mut [u64] {
+        match self {
+            Bitmap::Inline(words) => words,
+            Bitmap::Heap(words) => words,
+        }
+    }
+
+    #[inline(always)]
+    fn get(&self, ix: usize) -> bool {
+        self.words()[ix / 64] & (1 << (ix % 64)) != 0
+    }
+
+    #[inline(always)]
+    fn set(&mut self, ix: usize) {
+        self.words_mut()[ix / 64] |= 1 << (ix % 64);
+    }
+}
+
+/// The uninitialized tail of an item being constructed in place, which
+/// tracks the cells written so that they are dropped if the construction
+/// fails or panics. See [`Vec::push_with`](super::Vec::push_with).
+pub struct TailInit<'a, S> {
+    ptr: NonNull<S>,
+    length: usize,
+    count: usize,
+    initialized: Bitmap,
+    _phantom: PhantomData<&'a mut [S]>,
+}
+
+impl<'a, S> TailInit<'a, S> {
+    /// # Safety
+    ///
+    /// The pointer must be valid for writes of the length of cells, none of
+    /// which may hold a live value.
+    pub(super) unsafe fn new(ptr: NonNull<S>, length: usize) -> Self {
+        TailInit {
+            ptr,
+            length,
+            count: 0,
+            initialized: Bitmap::new(length),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Checks that every cell is written and releases them to the item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a cell was not written, in which case the written cells are
+    /// dropped.
+    pub(super) fn finish(mut self) {
+        if self.count != self.length {
+            panic!("Tail not fully initialized");
+        }
+        drop(mem::replace(&mut self.initialized, Bitmap::Inline([0; INLINE_WORDS])));
+        mem::forget(self);
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    fn check(&self, ix: usize) {
+        if ix >= self.length {
+            panic!("Out of bounds");
+        }
+    }
+
+    /// Returns the number of cells of the tail.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if the tail has no cells.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Checks if every cell has been written.
+    #[inline(always)]
+    pub fn is_complete(&self) -> bool {
+        self.count == self.length
+    }
+
+    /// Checks if the cell at the index has been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[inline(always)]
+    pub fn is_init(&self, ix: usize) -> bool {
+        self.check(ix);
+        self.initialized.get(ix)
+    }
+
+    /// Writes the cell at the index, dropping any value written before, and
+    /// returns a reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn write(&mut self, ix: usize, value: S) -> &mut S {
+        let init = self.is_init(ix);
+        let cell = unsafe { &mut *self.ptr.as_ptr().add(ix) };
+        if init {
+            *cell = value;
+        } else {
+            unsafe { (cell as *mut S).write(value) };
+            self.initialized.set(ix);
+            self.count += 1;
+        }
+        cell
+    }
+
+    /// Returns the cell at the index, if it has been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut S> {
+        if self.is_init(ix) {
+            Some(unsafe { &mut *self.ptr.as_ptr().add(ix) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns all cells as uninitialized, such as to be filled by FFI.
+    /// Cells written this way must be marked with
+    /// [`assume_init`](Self::assume_init); overwriting a written cell leaks
+    /// its value.
+    ///
+    /// # Safety
+    ///
+    /// Any cell already written, or marked with
+    /// [`assume_init`](Self::assume_init), must not be de-initialized
+    /// through the returned slice, as it is dropped with the tail.
+    #[inline(always)]
+    pub unsafe fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<S>] {
+        &mut *slice_from_raw_parts_mut(self.ptr.as_ptr() as *mut MaybeUninit<S>, self.length)
+    }
+
+    /// Marks the cells in the range as written.
+    ///
+    /// # Safety
+    ///
+    /// All cells in the range must hold a valid value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub unsafe fn assume_init(&mut self, range: impl RangeBounds<usize>) {
+        for ix in checked_range(self.length, range) {
+            if !self.is_init(ix) {
+                self.initialized.set(ix);
+                self.count += 1;
+            }
+        }
+    }
+}
+
+unsafe impl<S: Send> Send for TailInit<'_, S> {}
+unsafe impl<S: Sync> Sync for TailInit<'_, S> {}
+
+impl<S: Debug> Debug for TailInit<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: std::vec::Vec<Option<&S>> = (0..self.length)
+            .map(|ix| if self.is_init(ix) {
+                Some(unsafe { &*self.ptr.as_ptr().add(ix) })
+            } else {
+                None
+            })
+            .collect();
+        f
+            .debug_struct("TailInit")
+            .field("cells", &cells)
+            .finish()
+    }
+}
+
+impl<S> Drop for TailInit<'_, S> {
+    fn drop(&mut self) {
+        for ix in 0..self.length {
+            if self.is_init(ix) {
+                unsafe { drop_in_place(self.ptr.as_ptr().add(ix)) }
+            }
+        }
+    }
+}
@@ -1,14 +1,29 @@
-use std::ops::Range;
-use super::Vec;
+use std::{
+    marker::PhantomData,
+    ops::Range,
+    ptr::NonNull,
+};
+use super::{
+    View,
+    ViewMut,
+};
 
 mod traits;
 
 pub struct Iter<'a, V, T> {
     pub(super) iter: Range<usize>,
-    pub(super) ptr: &'a Vec<V, T>,
+    pub(super) ptr: View<'a, V, T>,
 }
 
 pub struct IterMut<'a, V, T> {
     pub(super) iter: Range<usize>,
-    pub(super) ptr: &'a mut Vec<V, T>,
+    pub(super) ptr: ViewMut<'a, V, T>,
+}
+
+/// Moves the cells out of a tail while mapping a [`Vec`](super::Vec). Any
+/// cells not taken are dropped with the iterator.
+pub struct TailDrain<'a, S> {
+    pub(super) iter: Range<usize>,
+    pub(super) ptr: NonNull<S>,
+    pub(super) _phantom: PhantomData<&'a mut S>,
 }
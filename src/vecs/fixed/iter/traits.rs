@@ -1,3 +1,8 @@
+use std::ptr::{
+    drop_in_place,
+    read,
+    slice_from_raw_parts_mut,
+};
 use crate::prelude::*;
 use super::{
     *,
@@ -8,7 +13,7 @@ impl<'a, T, S> From<IterMut<'a, T, S>> for Iter<'a, T, S> {
     fn from(iter: IterMut<'a, T, S>) -> Self {
         Iter {
             iter: iter.iter,
-            ptr: &*iter.ptr,
+            ptr: iter.ptr.into(),
         }
     }
 }
@@ -47,6 +52,18 @@ impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR> PartialEq<Iter<'_, TR, SR>> f
 
 impl<T: Eq, S: Eq> Eq for Iter<'_, T, S> {}
 
+impl<T: PartialOrd, S: PartialOrd> PartialOrd for Iter<'_, T, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Iterator::partial_cmp(self.clone(), other.clone())
+    }
+}
+
+impl<T: Ord, S: Ord> Ord for Iter<'_, T, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Iterator::cmp(self.clone(), other.clone())
+    }
+}
+
 impl<T: Hash, S: Hash> Hash for Iter<'_, T, S> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         for value in self.clone() {
@@ -81,3 +98,58 @@ impl<'a, T, S> Iterator for IterMut<'a, T, S> {
             })
     }
 }
+
+unsafe impl<S: Send> Send for TailDrain<'_, S> {}
+unsafe impl<S: Sync> Sync for TailDrain<'_, S> {}
+
+impl<S: Debug> Debug for TailDrain<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining = unsafe { &*slice_from_raw_parts_mut(
+            self.ptr.as_ptr().add(self.iter.start),
+            self.iter.len(),
+        ) };
+        f
+            .debug_tuple("TailDrain")
+            .field(&remaining)
+            .finish()
+    }
+}
+
+impl<S> Iterator for TailDrain<'_, S> {
+    type Item = S;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe { read(self.ptr.as_ptr().add(ix)) })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<S> DoubleEndedIterator for TailDrain<'_, S> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next_back()
+            .map(|ix| unsafe { read(self.ptr.as_ptr().add(ix)) })
+    }
+}
+
+impl<S> ExactSizeIterator for TailDrain<'_, S> {}
+
+impl<S> Drop for TailDrain<'_, S> {
+    fn drop(&mut self) {
+        let remaining = std::mem::replace(&mut self.iter, 0..0);
+        unsafe { drop_in_place(slice_from_raw_parts_mut(
+            self.ptr.as_ptr().add(remaining.start),
+            remaining.len(),
+        )) }
+    }
+}
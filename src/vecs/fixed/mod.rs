@@ -6,9 +6,13 @@ use crate::{
 
 mod traits;
 mod iter;
+mod init;
+mod view;
 mod implementation;
 
 pub use iter::*;
+pub use init::*;
+pub use view::*;
 
 /// Imitates a [`std::vec::Vec`] of a slice-based DST. All values have the
 /// same slice length, which allows random-access. Guarantied to store all
@@ -57,7 +61,6 @@ pub use iter::*;
 /// assert_eq!(item.value, Some("Name"));
 /// assert_eq!(item.tail, [1, 2, 3, 4]);
 /// ```
-
 pub struct Vec<T, S> {
     ptr: Alloc,
     length: usize,
@@ -88,4 +91,537 @@ mod test {
         }
         vec
     }
+
+    fn sample() -> Vec<u8, u16> {
+        let mut vec = Vec::new(3);
+        for ix in 0..5 {
+            vec.push(ix, (0..3).map(|column| ix as u16 * 10 + column));
+        }
+        vec
+    }
+
+    #[test]
+    fn index() {
+        let mut vec = sample();
+        assert_eq!(vec[2].value, 2);
+        assert_eq!(vec[2].tail, [20, 21, 22]);
+        assert_eq!(vec[(4, 1)], 41);
+
+        vec[1].value = 9;
+        vec[(1, 2)] = 0;
+        assert_eq!(vec.get(1).unwrap().value, 9);
+        assert_eq!(vec.get(1).unwrap().tail, [10, 11, 0]);
+    }
+
+    #[test]
+    fn push_longer() {
+        use std::rc::Rc;
+
+        let mut vec = Vec::<u8, u16>::new(2);
+        let mut values = 0..;
+        vec.push(0, values.by_ref());
+        vec.push(1, values.by_ref());
+        assert_eq!(values.next(), Some(4));
+        assert_eq!(vec[0].tail, [0, 1]);
+        assert_eq!(vec[1].tail, [2, 3]);
+
+        let rc = Rc::new(());
+        let mut vec = Vec::new(2);
+        vec.push(rc.clone(), vec![rc.clone(); 3]);
+        assert_eq!(Rc::strong_count(&rc), 4);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 (slice length 3) but the index is 5")]
+    fn index_out_of_bounds() {
+        let _ = &sample()[5];
+    }
+
+    #[test]
+    #[should_panic(expected = "the len is 5 (slice length 3) but the index is (1, 3)")]
+    fn cell_out_of_bounds() {
+        let _ = sample()[(1, 3)];
+    }
+
+    #[test]
+    fn remove_range() {
+        let values = |vec: &Vec<u8, u16>| vec
+            .iter()
+            .map(|handle| handle.value)
+            .collect::<std::vec::Vec<_>>();
+
+        let mut vec = sample();
+        vec.remove_range(1..4);
+        assert_eq!(values(&vec), [0, 4]);
+        assert_eq!(vec[1].tail, [40, 41, 42]);
+
+        let mut vec = sample();
+        vec.remove_range_replace(0..2);
+        assert_eq!(values(&vec), [3, 4, 2]);
+
+        let mut vec = sample();
+        vec.remove_range_replace(1..4);
+        assert_eq!(values(&vec), [0, 4]);
+
+        let mut vec = sample();
+        vec.remove_range(..);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn remove_range_drops() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut vec = Vec::new(1);
+        for _ in 0..5 {
+            vec.push(rc.clone(), [rc.clone()]);
+        }
+        // The tail after the range is shorter than the range
+        vec.remove_range_replace(1..4);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(Rc::strong_count(&rc), 5);
+        vec.remove_range(..1);
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn view() {
+        let mut vec = sample();
+        let view = vec.view(1..=3);
+        assert_eq!(view.len(), 3);
+        assert_eq!(view[0].value, 1);
+        assert_eq!(view[(2, 0)], 30);
+        assert_eq!(view.iter().map(|handle| handle.value).collect::<std::vec::Vec<_>>(), [1, 2, 3]);
+        assert_eq!(view.view(1..), vec.view(2..4));
+
+        let mut view = vec.view_mut(3..);
+        view[1].value = 7;
+        view[(0, 0)] = 99;
+        for handle in view {
+            handle.tail[2] = 0;
+        }
+        assert_eq!(vec[4].value, 7);
+        assert_eq!(vec[3].tail, [99, 31, 0]);
+        assert_eq!(vec[4].tail, [40, 41, 0]);
+    }
+
+    #[test]
+    fn ordering() {
+        use std::collections::BTreeSet;
+
+        let mut vec = sample();
+        assert!(vec[1] < vec[2]);
+        assert!(vec.view(0..2) < vec.view(1..2));
+        assert!(vec.iter() > vec.view(..4).iter());
+
+        let mut other = sample();
+        assert_eq!(vec.cmp(&other), std::cmp::Ordering::Equal);
+        other[(4, 2)] = 0;
+        assert!(vec > other);
+        other.pop();
+        other.push(9, [0, 0, 0]);
+        assert!(vec < other);
+
+        let mut set = BTreeSet::new();
+        while !vec.is_empty() {
+            set.insert(vec.pop_boxed());
+        }
+        assert_eq!(set.iter().map(|handle| handle.value).collect::<std::vec::Vec<_>>(), [0, 1, 2, 3, 4]);
+        assert!(set.contains(&sample().pop_boxed()));
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 6 out of range for length 5")]
+    fn view_out_of_bounds() {
+        sample().view(2..6);
+    }
+
+    #[test]
+    fn nested() {
+        use std::convert::TryFrom;
+
+        let nested: std::vec::Vec<(u8, std::vec::Vec<u16>)> = sample().into();
+        assert_eq!(nested[3], (3, vec![30, 31, 32]));
+        assert_eq!(Vec::try_from(nested).unwrap(), sample());
+
+        let boxed = sample().to_nested::<Box<[u16]>>();
+        assert_eq!(Vec::try_from(boxed).unwrap(), sample());
+
+        let ragged = vec![(0u8, vec![1u16, 2]), (1, vec![3, 4]), (2, vec![5])];
+        let error = Vec::try_from(ragged).unwrap_err();
+        assert_eq!(error, crate::RaggedError { index: 2, expected: 2, found: 1 });
+    }
+
+    #[test]
+    fn nested_array() {
+        let nested = vec![(1u32, [2u32, 3]), (4, [5, 6])];
+        let ptr = nested.as_ptr() as *const u8;
+        let vec = Vec::from(nested);
+        assert_eq!(vec.ptr.map(|(vec_ptr, _)| vec_ptr.as_ptr() as *const u8), Some(ptr));
+        assert_eq!(vec[1].tail, [5, 6]);
+
+        let vec = Vec::from(vec![(1u8, [String::from("a")]), (2, [String::from("b")])]);
+        assert_eq!(vec[1].value, 2);
+        assert_eq!(vec[(1, 0)], "b");
+    }
+
+    #[test]
+    fn columns() {
+        let mut vec = sample();
+        vec.insert_column(1, 7);
+        assert_eq!(vec[3].tail, [30, 7, 31, 32]);
+        vec.swap_columns(0, 3);
+        assert_eq!(vec[3].tail, [32, 7, 31, 30]);
+        vec.remove_column(1);
+        assert_eq!(vec[3].tail, [32, 31, 30]);
+        assert_eq!(vec.project(&[2, 0, 0]).get(4).unwrap().tail, [40, 42, 42]);
+        vec.resize_slice_length(1, 0);
+        assert_eq!(vec[3].tail, [32]);
+        vec.resize_slice_length(2, 5);
+        assert_eq!(vec[3].tail, [32, 5]);
+        assert_eq!(vec[3].value, 3);
+        assert_eq!(vec.len(), 5);
+
+        let mut vec = Vec::<(), String>::new(2);
+        vec.push((), vec![String::from("a"), String::from("b")]);
+        vec.remove_column(0);
+        vec.insert_column(1, String::from("c"));
+        assert_eq!(vec[0].tail, ["b", "c"]);
+    }
+
+    #[test]
+    fn map() {
+        let vec = sample();
+        let ptr = vec.ptr.map(|(ptr, _)| ptr);
+        let vec = vec.map_values(|value| value as i8 - 2);
+        assert_eq!(vec.ptr.map(|(ptr, _)| ptr), ptr);
+        assert_eq!(vec[0].value, -2);
+
+        let vec = vec.map_cells(|cell| cell.to_string());
+        assert_eq!(vec[4].tail, ["40", "41", "42"]);
+
+        let vec = vec.into_map(1, |value, mut tail| (value, tail.nth(1)));
+        assert_eq!(vec[4].tail, ["41"]);
+        assert_eq!(vec[1].value, -1);
+
+        let vec = sample();
+        let ptr = vec.ptr.map(|(ptr, _)| ptr);
+        let vec = vec.map_items(|value| value as u16 * 100, |cell| cell + 1);
+        assert_eq!(vec.ptr.map(|(ptr, _)| ptr), ptr);
+        assert_eq!(vec[3].value, 300);
+        assert_eq!(vec[3].tail, [31, 32, 33]);
+    }
+
+    #[test]
+    fn map_panic() {
+        use std::{
+            panic::{
+                AssertUnwindSafe,
+                catch_unwind,
+            },
+            rc::Rc,
+        };
+
+        let rc = Rc::new(());
+        let mut vec = Vec::new(2);
+        for _ in 0..4 {
+            vec.push(rc.clone(), vec![rc.clone(), rc.clone()]);
+        }
+        let mut count = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| vec.map_values(|value| {
+            count += 1;
+            if count == 3 {
+                panic!("Mapping");
+            }
+            value
+        })));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn push_with() {
+        use std::{
+            panic::{
+                AssertUnwindSafe,
+                catch_unwind,
+            },
+            rc::Rc,
+        };
+
+        let mut vec = sample();
+        vec.push_with(5, |tail| {
+            for column in [2, 0, 1] {
+                tail.write(column, 50 + column as u16);
+            }
+            Ok::<_, ()>(())
+        }).unwrap();
+        assert_eq!(vec[5].tail, [50, 51, 52]);
+
+        assert_eq!(vec.insert_with(1, 9, |tail| {
+            tail.write(1, 0);
+            Err("Parse")
+        }), Err("Parse"));
+        vec.insert_with(1, 9, |tail| {
+            unsafe {
+                for (ix, cell) in tail.as_uninit_mut().iter_mut().enumerate() {
+                    cell.write(90 + ix as u16);
+                }
+                tail.assume_init(..);
+            }
+            Ok::<_, ()>(())
+        }).unwrap();
+        assert_eq!(vec.iter().map(|handle| handle.value).collect::<std::vec::Vec<_>>(), [0, 9, 1, 2, 3, 4, 5]);
+        assert_eq!(vec[1].tail, [90, 91, 92]);
+        assert_eq!(vec[2].tail, [10, 11, 12]);
+
+        let rc = Rc::new(());
+        let mut vec = Vec::new(3);
+        vec.push(rc.clone(), vec![rc.clone(); 3]);
+        let result = vec.push_with(rc.clone(), |tail| {
+            tail.write(2, rc.clone());
+            tail.write(2, rc.clone());
+            tail.write(0, rc.clone());
+            assert!(!tail.is_complete());
+            Err(())
+        });
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 5);
+        let result = catch_unwind(AssertUnwindSafe(|| vec.insert_with(0, rc.clone(), |tail| {
+            tail.write(1, rc.clone());
+            Ok::<_, ()>(())
+        })));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&rc), 5);
+        assert_eq!(vec.len(), 1);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tail not fully initialized")]
+    fn push_with_incomplete() {
+        let _ = sample().push_with(5, |tail| {
+            tail.write(0, 0);
+            Ok::<_, ()>(())
+        });
+    }
+
+    #[test]
+    fn push_with_long() {
+        let mut vec = Vec::<u8, u16>::new(300);
+        vec.push_with(1, |tail| {
+            for ix in (0..300).rev() {
+                tail.write(ix, ix as u16);
+            }
+            assert!(tail.is_complete());
+            Ok::<_, ()>(())
+        }).unwrap();
+        assert_eq!(vec[0].tail[299], 299);
+    }
+
+    #[test]
+    fn from_slice() {
+        let mut vec = sample();
+        vec.push_from_slice(5, &[50, 51, 52]);
+        vec.push_copy_from_slice(6, &[60, 61, 62]);
+        assert_eq!(vec[5].tail, [50, 51, 52]);
+        assert_eq!(vec[6].tail, [60, 61, 62]);
+
+        vec.extend_copy_from_within(5..);
+        vec.extend_from_within(..=1);
+        assert_eq!(vec.len(), 11);
+        assert_eq!(vec[8].value, 6);
+        assert_eq!(vec[8].tail, [60, 61, 62]);
+        assert_eq!(vec[10].tail, [10, 11, 12]);
+
+        let mut strings = Vec::<String, String>::new(2);
+        strings.push_from_slice(String::from("a"), &[String::from("b"), String::from("c")]);
+        strings.extend_from_within(..);
+        strings.extend_from_within(..);
+        assert_eq!(strings.len(), 4);
+        assert_eq!(strings[3].value, "a");
+        assert_eq!(strings[(3, 1)], "c");
+    }
+
+    #[test]
+    #[should_panic(expected = "Length mismatch")]
+    fn from_slice_mismatch() {
+        sample().push_copy_from_slice(5, &[50, 51]);
+    }
+
+    #[test]
+    fn move_from() {
+        let mut vec = sample();
+        let mut other = Vec::new(3);
+        for ix in 0..4 {
+            other.push(ix + 10, [ix as u16 + 100; 3]);
+        }
+        vec.move_from(&mut other, 1..3);
+        assert_eq!(vec.len(), 7);
+        assert_eq!(vec[5].value, 11);
+        assert_eq!(vec[6].tail, [102; 3]);
+        assert_eq!(other.len(), 2);
+        assert_eq!(other[1].value, 13);
+    }
+
+    #[test]
+    fn move_from_empty() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut vec = Vec::new(2);
+        let mut other = Vec::new(2);
+        for ix in 0..4 {
+            other.push((ix, rc.clone()), [rc.clone(), rc.clone()]);
+        }
+        // Moving into an empty vec reads from the other allocation
+        vec.move_from(&mut other, 1..);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec[0].value.0, 1);
+        assert_eq!(vec[2].value.0, 3);
+        assert_eq!(other.len(), 1);
+        assert_eq!(Rc::strong_count(&rc), 13);
+        drop(other);
+        assert_eq!(Rc::strong_count(&rc), 10);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn spare_capacity() {
+        let mut vec = sample();
+        vec.reserve_exact(2);
+        let capacity = vec.capacity();
+        assert_eq!(vec.as_ptr(), &vec[0] as *const _);
+        let mut spare = vec.spare_capacity_mut();
+        assert_eq!(spare.len(), capacity - 5);
+        for (ix, handle) in spare.iter_mut().take(2).enumerate() {
+            handle.value.write(ix as u8 + 5);
+            for (column, cell) in handle.tail.iter_mut().enumerate() {
+                cell.write((ix as u16 + 5) * 10 + column as u16);
+            }
+        }
+        unsafe { vec.set_len(7) };
+        assert_eq!(vec[6].value, 6);
+        assert_eq!(vec[6].tail, [60, 61, 62]);
+        assert_eq!(vec.spare_capacity_mut().len(), capacity - 7);
+        let second = unsafe { &*(vec.as_ptr() as *const u8).add(vec.item_size()) };
+        assert_eq!(second as *const u8, &vec[1] as *const _ as *const u8);
+
+        let mut empty = Vec::<u64, u8>::new(3);
+        assert!(empty.spare_capacity_mut().is_empty());
+        assert_eq!(empty.as_ptr() as *const u8 as usize % 8, 0);
+    }
+
+    #[test]
+    fn transpose() {
+        let mut vec = Vec::<(), usize>::new(40);
+        for row in 0..70 {
+            vec.push((), (0..40).map(|column| row * 100 + column));
+        }
+        let transposed = vec.transpose();
+        assert_eq!(transposed.len(), 40);
+        assert_eq!(transposed.slice_length(), 70);
+        assert_eq!(transposed[(39, 69)], 6939);
+        assert_eq!(transposed[(3, 65)], 6503);
+        assert_eq!(transposed.transpose(), vec);
+
+        let (values, transposed) = sample()
+            .map_cells(|cell| cell.to_string())
+            .into_transpose();
+        assert_eq!(values, [0, 1, 2, 3, 4]);
+        assert_eq!(transposed[2].tail, ["2", "12", "22", "32", "42"]);
+
+        let transposed = Vec::<(), u32>::new(3).transpose();
+        assert_eq!(transposed.len(), 3);
+        assert_eq!(transposed.slice_length(), 0);
+        let (values, transposed) = Vec::<u8, u32>::new(3).into_transpose();
+        assert!(values.is_empty());
+        assert_eq!(transposed.len(), 3);
+        assert_eq!(transposed.transpose().len(), 0);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = Vec::<(), u8>::new(0);
+        assert_eq!(vec.capacity(), usize::MAX);
+        for _ in 0..10 {
+            vec.push((), []);
+        }
+        vec.insert_default(3);
+        vec.remove_range(0..2);
+        assert_eq!(vec.len(), 9);
+        assert_eq!(vec.pop_boxed().tail, [0u8; 0]);
+        assert_eq!(vec.clone(), vec);
+        assert_eq!(vec.transpose().len(), 0);
+
+        let mut vec = Vec::<(), ()>::new(4);
+        vec.push_default();
+        vec.insert_column(0, ());
+        assert_eq!(vec[0].tail.len(), 5);
+        let vec = vec.map_cells(|()| 1u8);
+        assert_eq!(vec[0].tail, [1; 5]);
+        let vec = vec.map_cells(|_| ());
+        assert_eq!(vec.len(), 1);
+    }
+
+    #[test]
+    fn flat() {
+        use crate::FlatLengthError;
+
+        let flat = [1u16, 2, 3, 4, 5, 6];
+        let view = View::from_flat(&flat, 3).unwrap();
+        assert_eq!(view.len(), 2);
+        assert_eq!(view[1].tail, [4, 5, 6]);
+        assert!(view.column(1).copied().eq([2, 5]));
+        assert_eq!(
+            View::<(), u16>::from_flat(&flat, 4).unwrap_err(),
+            FlatLengthError { length: 6, slice_length: 4 },
+        );
+        assert_eq!(View::<(), u16>::from_flat(&[], 0).unwrap().len(), 0);
+
+        let mut flat = flat;
+        let mut view = ViewMut::from_flat_mut(&mut flat, 2).unwrap();
+        view[(2, 1)] = 60;
+        assert_eq!(view.len(), 3);
+        assert_eq!(flat[5], 60);
+
+        assert!(sample().column(2).copied().rev().eq([42, 32, 22, 12, 2]));
+    }
+
+    #[test]
+    fn flat_vec() {
+        let mut flat = std::vec::Vec::with_capacity(8);
+        flat.extend(0u32..6);
+        let ptr = flat.as_ptr();
+        let mut vec = Vec::from_flat_vec(flat, 2).unwrap();
+        assert_eq!(vec.capacity(), 4);
+        assert_eq!(vec.as_flat_slice().as_ptr(), ptr);
+        assert_eq!(vec[2].tail, [4, 5]);
+        vec.extend_from_flat(&[6, 7, 8, 9]).unwrap();
+        assert!(vec.extend_from_flat(&[1]).is_err());
+        vec.as_flat_slice_mut()[0] = 10;
+        assert_eq!(vec.as_flat_slice(), [10, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let vec = Vec::from_flat_vec(vec![String::from("a"); 3], 1).unwrap();
+        assert_eq!(vec.len(), 3);
+        let flat = vec.into_flat_vec();
+        assert_eq!(flat, ["a", "a", "a"]);
+
+        let flat = Vec::from_flat_vec(vec![0u8; 7], 2).unwrap_err();
+        assert_eq!(flat.length, 7);
+
+        let mut flat = std::vec::Vec::with_capacity(5);
+        flat.extend(0u8..4);
+        let vec = Vec::from_flat_vec(flat, 2).unwrap();
+        assert_eq!(vec[1].tail, [2, 3]);
+        assert_eq!(Vec::from_flat_vec(vec![(); 4], 2).unwrap().len(), 2);
+        assert_eq!(Vec::<(), ()>::new(3).into_flat_vec().len(), 0);
+    }
 }
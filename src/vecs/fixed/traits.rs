@@ -1,11 +1,32 @@
 use std::{
-    alloc::dealloc,
+    alloc::Layout,
+    convert::TryFrom,
     hint::unreachable_unchecked,
+    mem::{
+        needs_drop,
+        ManuallyDrop,
+        MaybeUninit,
+    },
+    num::NonZeroUsize,
+    ops::{
+        Index,
+        IndexMut,
+    },
+    ptr::{
+        addr_of,
+        NonNull,
+    },
 };
 use crate::{
+    alloc::dealloc_layout,
+    error::RaggedError,
     Handle,
     prelude::*,
-    util::CopyNonoverlappingFn,
+    util::{
+        cell_out_of_bounds,
+        CopyNonoverlappingFn,
+        index_out_of_bounds,
+    },
 };
 use super::*;
 
@@ -21,6 +42,20 @@ impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR> PartialEq<Vec<TR, SR>> for Ve
 
 impl<T: Eq, S: Eq> Eq for Vec<T, S> {}
 
+impl<T: PartialOrd, S: PartialOrd> PartialOrd for Vec<T, S> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, S: Ord> Ord for Vec<T, S> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
 impl<T: Hash, S: Hash> Hash for Vec<T, S> {
     #[inline(always)]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -47,10 +82,7 @@ impl<'a, T, S> IntoIterator for &'a Vec<T, S> {
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        Iter {
-            iter: 0..(self.length),
-            ptr: self,
-        }
+        self.as_view().into_iter()
     }
 }
 
@@ -60,28 +92,72 @@ impl<'a, T, S> IntoIterator for &'a mut Vec<T, S> {
 
     #[inline(always)]
     fn into_iter(self) -> Self::IntoIter {
-        IterMut {
-            iter: 0..(self.length),
-            ptr: self,
+        self.as_view_mut().into_iter()
+    }
+}
+
+impl<T, S> Index<usize> for Vec<T, S> {
+    type Output = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn index(&self, ix: usize) -> &Self::Output {
+        match self.get(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> IndexMut<usize> for Vec<T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, ix: usize) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, length, slice),
+        }
+    }
+}
+
+impl<T, S> Index<(usize, usize)> for Vec<T, S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, (ix, column): (usize, usize)) -> &Self::Output {
+        match self.get(ix).and_then(|handle| handle.tail.get(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> IndexMut<(usize, usize)> for Vec<T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, (ix, column): (usize, usize)) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix).and_then(|handle| handle.tail.get_mut(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, length, slice),
         }
     }
 }
 
 impl<T, S> Drop for Vec<T, S> {
     fn drop(&mut self) {
-        if !self.ptr.is_some() {
+        if self.ptr.is_none() {
             return;
         }
-        while !self.is_empty() {
-            self.pop()
+        if needs_drop::<T>() || needs_drop::<S>() {
+            while !self.is_empty() {
+                self.pop()
+            }
         }
         if let Some((ptr, capacity)) = self.ptr.take() {
-            unsafe { dealloc(
-                ptr.as_ptr(),
+            unsafe { dealloc_layout(
+                ptr,
                 Handle::<T, [S]>::layout_slice(self.slice, capacity),
             ) }
         }
-
     }
 }
 
@@ -95,7 +171,9 @@ impl<T: Copy, S: Copy> Clone for Vec<T, S> {
         } = self;
         let mut new = Self::new(slice);
         if let Some((ptr, capacity)) = ptr {
-            new.alloc_grow(capacity);
+            if new.capacity() < capacity.get() {
+                new.alloc_grow(capacity);
+            }
             if let Some((new_ptr, _)) = new.ptr {
                 new.shift_memory::<CopyNonoverlappingFn>(new_ptr, 0, ptr, 0, length);
             } else {
@@ -106,4 +184,107 @@ impl<T: Copy, S: Copy> Clone for Vec<T, S> {
         }
         new
     }
-}
\ No newline at end of file
+}
+
+fn try_from_nested<T, S, C>(nested: std::vec::Vec<(T, C)>) -> Result<Vec<T, S>, RaggedError>
+where
+    C: AsRef<[S]> + IntoIterator<Item=S>,
+{
+    let expected = nested.first().map_or(0, |(_, tail)| tail.as_ref().len());
+    let ragged = nested
+        .iter()
+        .map(|(_, tail)| tail.as_ref().len())
+        .enumerate()
+        .find(|&(_, found)| found != expected);
+    if let Some((index, found)) = ragged {
+        return Err(RaggedError {
+            index,
+            expected,
+            found,
+        });
+    }
+
+    let mut vec = Vec::new(expected);
+    vec.reserve_exact(nested.len());
+    for (value, tail) in nested {
+        vec.push(value, tail);
+    }
+    Ok(vec)
+}
+
+impl<T, S> TryFrom<std::vec::Vec<(T, std::vec::Vec<S>)>> for Vec<T, S> {
+    type Error = RaggedError;
+
+    /// Moves every item, failing if the tails are not all the same length.
+    /// An empty input produces a tail length of zero.
+    #[inline(always)]
+    fn try_from(nested: std::vec::Vec<(T, std::vec::Vec<S>)>) -> Result<Self, Self::Error> {
+        try_from_nested(nested)
+    }
+}
+
+impl<T, S> TryFrom<std::vec::Vec<(T, Box<[S]>)>> for Vec<T, S> {
+    type Error = RaggedError;
+
+    /// Moves every item, failing if the tails are not all the same length.
+    /// An empty input produces a tail length of zero.
+    #[inline(always)]
+    fn try_from(nested: std::vec::Vec<(T, Box<[S]>)>) -> Result<Self, Self::Error> {
+        try_from_nested(nested)
+    }
+}
+
+/// Checks if a `(T, [S; N])` is laid out exactly as the equivalent
+/// [`Handle`], such that an allocation of one can be reused as the other.
+fn same_layout<T, S, const N: usize>() -> bool {
+    let tuple = MaybeUninit::<(T, [S; N])>::uninit();
+    let handle = MaybeUninit::<Handle<T, [S; N]>>::uninit();
+    let tuple = tuple.as_ptr();
+    let handle = handle.as_ptr();
+    let layout = Layout::new::<(T, [S; N])>();
+    unsafe {
+        layout.size() != 0
+            && layout.size() == Handle::<T, [S]>::size_slice(N)
+            && layout.align() == Handle::<T, [S]>::alignment_slice(N)
+            && addr_of!((*tuple).0) as usize - tuple as usize
+                == addr_of!((*handle).value) as usize - handle as usize
+            && addr_of!((*tuple).1) as usize - tuple as usize
+                == addr_of!((*handle).tail) as usize - handle as usize
+    }
+}
+
+impl<T, S, const N: usize> From<std::vec::Vec<(T, [S; N])>> for Vec<T, S> {
+    /// Reuses the allocation when the tuple has the same layout as the
+    /// [`Handle`], which is typical; otherwise, moves every item into a new
+    /// allocation.
+    fn from(nested: std::vec::Vec<(T, [S; N])>) -> Self {
+        if same_layout::<T, S, N>() {
+            if let Some(capacity) = NonZeroUsize::new(nested.capacity()) {
+                let mut nested = ManuallyDrop::new(nested);
+                let ptr = unsafe { NonNull::new_unchecked(nested.as_mut_ptr() as *mut u8) };
+                return unsafe { Vec::from_raw_parts(Some((ptr, capacity)), nested.len(), N) };
+            }
+        }
+
+        let mut vec = Vec::new(N);
+        vec.reserve_exact(nested.len());
+        for (value, tail) in nested {
+            vec.push(value, tail);
+        }
+        vec
+    }
+}
+
+impl<T, S> From<Vec<T, S>> for std::vec::Vec<(T, std::vec::Vec<S>)> {
+    #[inline(always)]
+    fn from(vec: Vec<T, S>) -> Self {
+        vec.into_nested()
+    }
+}
+
+impl<T, S> From<Vec<T, S>> for std::vec::Vec<(T, Box<[S]>)> {
+    #[inline(always)]
+    fn from(vec: Vec<T, S>) -> Self {
+        vec.into_nested()
+    }
+}
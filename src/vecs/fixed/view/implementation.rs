@@ -0,0 +1,258 @@
+use std::{
+    ops::RangeBounds,
+    ptr::NonNull,
+};
+use crate::{
+    error::FlatLengthError,
+    Handle,
+};
+use super::{
+    *,
+    super::{
+        implementation::{
+            checked_range,
+            flat_items,
+        },
+        Iter,
+        IterMut,
+    },
+};
+
+impl<'a, S> View<'a, (), S> {
+    /// Views a flat buffer of cells as items of the tail length, without
+    /// copying. The buffer is read row after row.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the buffer length is not a multiple of the tail length.
+    pub fn from_flat(flat: &'a [S], slice_length: usize) -> Result<Self, FlatLengthError> {
+        Ok(View {
+            length: flat_items(flat.len(), slice_length)?,
+            ptr: NonNull::from(flat).cast(),
+            slice: slice_length,
+            _phantom: Default::default(),
+        })
+    }
+}
+
+impl<'a, S> ViewMut<'a, (), S> {
+    /// Views a flat buffer of cells as items of the tail length, without
+    /// copying. The buffer is read row after row.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the buffer length is not a multiple of the tail length.
+    pub fn from_flat_mut(flat: &'a mut [S], slice_length: usize) -> Result<Self, FlatLengthError> {
+        Ok(ViewMut {
+            length: flat_items(flat.len(), slice_length)?,
+            ptr: NonNull::from(flat).cast(),
+            slice: slice_length,
+            _phantom: Default::default(),
+        })
+    }
+}
+
+impl<'a, T, S> View<'a, T, S> {
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.slice
+    }
+
+    /// Returns a reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&'a Handle<T, [S]>> {
+        if ix >= self.length {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked(ix) })
+        }
+    }
+
+    /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`len`](Self::len).
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &'a Handle<T, [S]> {
+        &*get_ix!(T S, self.ptr, self.slice, ix,)
+    }
+
+    /// Returns an iterator that provides references.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'a, T, S> {
+        Iter {
+            iter: 0..(self.length),
+            ptr: *self,
+        }
+    }
+
+    /// Returns an iterator that provides references to the cells of the
+    /// column in each item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is not less than the tail length.
+    #[inline(always)]
+    pub fn column(&self, column: usize) -> Column<'a, T, S> {
+        if column >= self.slice {
+            panic!("Out of bounds column");
+        }
+        Column {
+            iter: 0..(self.length),
+            column,
+            ptr: *self,
+        }
+    }
+
+    /// Returns a narrower view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    pub fn view(&self, range: impl RangeBounds<usize>) -> View<'a, T, S> {
+        let range = checked_range(self.length, range);
+        View {
+            ptr: unsafe { NonNull::new_unchecked(
+                get_ix!(T S, self.ptr, self.slice, range.start,) as *mut u8
+            ) },
+            length: range.len(),
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<'a, T, S> ViewMut<'a, T, S> {
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.slice
+    }
+
+    /// Returns a read-only view of the same items.
+    #[inline(always)]
+    pub fn as_view(&self) -> View<'_, T, S> {
+        View {
+            ptr: self.ptr,
+            length: self.length,
+            slice: self.slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns a reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&Handle<T, [S]>> {
+        self.as_view().get(ix)
+    }
+
+    /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`len`](Self::len).
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, [S]> {
+        &*get_ix!(T S, self.ptr, self.slice, ix,)
+    }
+
+    /// Returns a mutable reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut Handle<T, [S]>> {
+        if ix >= self.length {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked_mut(ix) })
+        }
+    }
+
+    /// Returns a mutable reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than [`len`](Self::len).
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, [S]> {
+        &mut *get_ix!(T S, self.ptr, self.slice, ix,)
+    }
+
+    /// Returns an iterator that provides references.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, S> {
+        self.as_view().iter()
+    }
+
+    /// Returns an iterator that provides mutable references.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, S> {
+        IterMut {
+            iter: 0..(self.length),
+            ptr: self.view_mut(..),
+        }
+    }
+
+    /// Returns an iterator that provides references to the cells of the
+    /// column in each item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is not less than the tail length.
+    #[inline(always)]
+    pub fn column(&self, column: usize) -> Column<'_, T, S> {
+        self.as_view().column(column)
+    }
+
+    /// Returns a narrower read-only view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn view(&self, range: impl RangeBounds<usize>) -> View<'_, T, S> {
+        self.as_view().view(range)
+    }
+
+    /// Returns a narrower mutable view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    pub fn view_mut(&mut self, range: impl RangeBounds<usize>) -> ViewMut<'_, T, S> {
+        let View { ptr, length, slice, .. } = self.view(range);
+        ViewMut {
+            ptr,
+            length,
+            slice,
+            _phantom: Default::default(),
+        }
+    }
+}
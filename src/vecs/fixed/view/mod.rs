@@ -0,0 +1,51 @@
+use std::{
+    marker::PhantomData,
+    ops::Range,
+    ptr::NonNull,
+};
+use crate::Handle;
+
+mod traits;
+mod implementation;
+
+/// A borrowed, contiguous range of items of a [`Vec`](super::Vec). This is
+/// what a `&[T]` is to a [`std::vec::Vec`], except that the row-count and
+/// the tail-length can not be stored in a single fat-pointer, so it is
+/// passed around by value instead of by reference.
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::FixedVec;
+///
+/// let mut vec = FixedVec::<u8, u16>::new(2);
+/// for ix in 0..4 {
+///     vec.push(ix, [ix as u16, 10]);
+/// }
+/// let view = vec.view(1..3);
+/// assert_eq!(view.len(), 2);
+/// assert_eq!(view[0].value, 1);
+/// assert_eq!(view[(1, 0)], 2);
+/// ```
+pub struct View<'a, T, S> {
+    pub(crate) ptr: NonNull<u8>,
+    pub(crate) length: usize,
+    pub(crate) slice: usize,
+    pub(crate) _phantom: PhantomData<&'a Handle<T, [S]>>,
+}
+
+/// A mutably borrowed, contiguous range of items of a [`Vec`](super::Vec).
+/// See [`View`].
+pub struct ViewMut<'a, T, S> {
+    pub(crate) ptr: NonNull<u8>,
+    pub(crate) length: usize,
+    pub(crate) slice: usize,
+    pub(crate) _phantom: PhantomData<&'a mut Handle<T, [S]>>,
+}
+
+/// Iterator over references of the cells in one column of a [`View`].
+pub struct Column<'a, T, S> {
+    pub(super) iter: Range<usize>,
+    pub(super) column: usize,
+    pub(super) ptr: View<'a, T, S>,
+}
@@ -0,0 +1,265 @@
+use std::ops::{
+    Index,
+    IndexMut,
+};
+use crate::{
+    Handle,
+    prelude::*,
+    util::{
+        cell_out_of_bounds,
+        index_out_of_bounds,
+    },
+};
+use super::{
+    *,
+    super::{
+        Iter,
+        IterMut,
+    },
+};
+
+unsafe impl<T, S> Send for View<'_, T, S> where Handle<T, [S]>: Sync {}
+unsafe impl<T, S> Sync for View<'_, T, S> where Handle<T, [S]>: Sync {}
+unsafe impl<T, S> Send for ViewMut<'_, T, S> where Handle<T, [S]>: Send {}
+unsafe impl<T, S> Sync for ViewMut<'_, T, S> where Handle<T, [S]>: Sync {}
+
+impl<T, S> Copy for View<'_, T, S> {}
+
+impl<T, S> Clone for View<'_, T, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, S> From<ViewMut<'a, T, S>> for View<'a, T, S> {
+    #[inline(always)]
+    fn from(view: ViewMut<'a, T, S>) -> Self {
+        View {
+            ptr: view.ptr,
+            length: view.length,
+            slice: view.slice,
+            _phantom: Default::default(),
+        }
+    }
+}
+
+impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR> PartialEq<View<'_, TR, SR>> for View<'_, TL, SL> {
+    #[inline(always)]
+    fn eq(&self, other: &View<'_, TR, SR>) -> bool {
+        self.length == other.length
+            && self.iter() == other.iter()
+    }
+}
+
+impl<T: Eq, S: Eq> Eq for View<'_, T, S> {}
+
+impl<T: PartialOrd, S: PartialOrd> PartialOrd for View<'_, T, S> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, S: Ord> Ord for View<'_, T, S> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash, S: Hash> Hash for View<'_, T, S> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter().hash(state)
+    }
+}
+
+impl<T: Debug, S: Debug> Debug for View<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_struct("View")
+            .field("values", &self.iter())
+            .finish()
+    }
+}
+
+impl<T: Debug, S: Debug> Debug for ViewMut<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_struct("ViewMut")
+            .field("values", &self.iter())
+            .finish()
+    }
+}
+
+impl<T, S> Index<usize> for View<'_, T, S> {
+    type Output = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn index(&self, ix: usize) -> &Self::Output {
+        match self.get(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> Index<(usize, usize)> for View<'_, T, S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, (ix, column): (usize, usize)) -> &Self::Output {
+        match self.get(ix).and_then(|handle| handle.tail.get(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> Index<usize> for ViewMut<'_, T, S> {
+    type Output = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn index(&self, ix: usize) -> &Self::Output {
+        match self.get(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> IndexMut<usize> for ViewMut<'_, T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, ix: usize) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, length, slice),
+        }
+    }
+}
+
+impl<T, S> Index<(usize, usize)> for ViewMut<'_, T, S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, (ix, column): (usize, usize)) -> &Self::Output {
+        match self.get(ix).and_then(|handle| handle.tail.get(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, self.length, self.slice),
+        }
+    }
+}
+
+impl<T, S> IndexMut<(usize, usize)> for ViewMut<'_, T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, (ix, column): (usize, usize)) -> &mut Self::Output {
+        let (length, slice) = (self.length, self.slice);
+        match self.get_mut(ix).and_then(|handle| handle.tail.get_mut(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, length, slice),
+        }
+    }
+}
+
+impl<'a, T, S> IntoIterator for View<'a, T, S> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = Iter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S> IntoIterator for &View<'a, T, S> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = Iter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S> IntoIterator for ViewMut<'a, T, S> {
+    type Item = &'a mut Handle<T, [S]>;
+    type IntoIter = IterMut<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            iter: 0..(self.length),
+            ptr: self,
+        }
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a ViewMut<'_, T, S> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = Iter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a mut ViewMut<'_, T, S> {
+    type Item = &'a mut Handle<T, [S]>;
+    type IntoIter = IterMut<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, S> Clone for Column<'_, T, S> {
+    fn clone(&self) -> Self {
+        Column {
+            iter: self.iter.clone(),
+            column: self.column,
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<T, S: Debug> Debug for Column<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_list()
+            .entries(self.clone())
+            .finish()
+    }
+}
+
+impl<'a, T, S> Iterator for Column<'a, T, S> {
+    type Item = &'a S;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe { self.ptr.get_unchecked(ix).tail.get_unchecked(self.column) })
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, S> DoubleEndedIterator for Column<'_, T, S> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next_back()
+            .map(|ix| unsafe { self.ptr.get_unchecked(ix).tail.get_unchecked(self.column) })
+    }
+}
+
+impl<T, S> ExactSizeIterator for Column<'_, T, S> {}
@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use crate::{
+    FixedVec,
+    FixedVecIter,
+    Handle,
+};
+use super::*;
+
+impl<T: Ord, S> Compare<T, S> for ValueOrder {
+    #[inline(always)]
+    fn compare(&self, left: &Handle<T, [S]>, right: &Handle<T, [S]>) -> Ordering {
+        left.value.cmp(&right.value)
+    }
+}
+
+impl<T, S, F> Compare<T, S> for F
+    where F: Fn(&Handle<T, [S]>, &Handle<T, [S]>) -> Ordering
+{
+    #[inline(always)]
+    fn compare(&self, left: &Handle<T, [S]>, right: &Handle<T, [S]>) -> Ordering {
+        self(left, right)
+    }
+}
+
+impl<T: Ord, S> BinaryHeap<T, S> {
+    /// Creates a new [`BinaryHeap`] ordered by the items' values, that can
+    /// contain items where the tail length is as provided.
+    #[inline(always)]
+    pub fn new(slice_length: usize) -> Self {
+        Self::with_comparator(slice_length, ValueOrder)
+    }
+}
+
+impl<T, S, C: Compare<T, S>> BinaryHeap<T, S, C> {
+    /// Creates a new [`BinaryHeap`] ordered by the provided comparator, that
+    /// can contain items where the tail length is as provided.
+    #[inline(always)]
+    pub fn with_comparator(slice_length: usize, compare: C) -> Self {
+        BinaryHeap {
+            data: FixedVec::new(slice_length),
+            compare,
+        }
+    }
+
+    /// Converts the [`FixedVec`] into a heap ordered by the provided
+    /// comparator, reordering the items as needed.
+    pub fn from_vec_with_comparator(data: FixedVec<T, S>, compare: C) -> Self {
+        let mut heap = BinaryHeap {
+            data,
+            compare,
+        };
+        for ix in (0..(heap.data.len() / 2)).rev() {
+            heap.sift_down(ix, heap.data.len());
+        }
+        heap
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.data.slice_length()
+    }
+
+    /// Returns the maximum number of items before a reallocation is needed.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Will insure it has enough space for the specified number of items.
+    /// See [`FixedVec::reserve`].
+    #[inline(always)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional)
+    }
+
+    /// Adds an item using an iterator containing at least enough values to
+    /// populate the DST's slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count.
+    pub fn push(&mut self, value: T, slice: impl IntoIterator<Item=S>) {
+        self.data.push(value, slice);
+        self.sift_up(self.data.len() - 1);
+    }
+
+    /// Returns a reference to the greatest item, or `None` if empty.
+    #[inline(always)]
+    pub fn peek(&self) -> Option<&Handle<T, [S]>> {
+        self.data.get(0)
+    }
+
+    /// Returns mutable access to the greatest item, or `None` if empty.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, S, C>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut {
+                heap: self,
+                sift: false,
+            })
+        }
+    }
+
+    /// Removes the greatest item and returns it, or `None` if empty.
+    pub fn pop_boxed(&mut self) -> Option<Box<Handle<T, [S]>>> {
+        if self.is_empty() {
+            return None;
+        }
+        self.remove_top();
+        Some(self.data.pop_boxed())
+    }
+
+    /// Removes the greatest item as if the slice part was immediately
+    /// dropped, but returning the value, or `None` if empty.
+    pub fn pop_value(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.remove_top();
+        Some(self.data.pop_value())
+    }
+
+    /// Returns an iterator that provides references, in arbitrary order.
+    #[inline(always)]
+    pub fn iter(&self) -> FixedVecIter<'_, T, S> {
+        self.data.iter()
+    }
+
+    /// Returns the underlying [`FixedVec`], in arbitrary order.
+    #[inline(always)]
+    pub fn into_vec(self) -> FixedVec<T, S> {
+        self.data
+    }
+
+    /// Returns the underlying [`FixedVec`], sorted in ascending order.
+    pub fn into_sorted_vec(mut self) -> FixedVec<T, S> {
+        for end in (1..self.data.len()).rev() {
+            self.data.swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.data
+    }
+
+    /// Moves the greatest item to the end, and restores the heap for the
+    /// items before it.
+    fn remove_top(&mut self) {
+        let end = self.data.len() - 1;
+        self.data.swap(0, end);
+        self.sift_down(0, end);
+    }
+
+    #[inline(always)]
+    fn is_greater(&self, left: usize, right: usize) -> bool {
+        let (left, right) = unsafe {(
+            self.data.get_unchecked(left),
+            self.data.get_unchecked(right),
+        )};
+        self.compare.compare(left, right) == Ordering::Greater
+    }
+
+    fn sift_up(&mut self, mut ix: usize) {
+        while ix > 0 {
+            let parent = (ix - 1) / 2;
+            if !self.is_greater(ix, parent) {
+                return;
+            }
+            self.data.swap(ix, parent);
+            ix = parent;
+        }
+    }
+
+    pub(super) fn sift_down(&mut self, mut ix: usize, end: usize) {
+        loop {
+            let mut child = 2 * ix + 1;
+            if child >= end {
+                return;
+            }
+            if child + 1 < end && self.is_greater(child + 1, child) {
+                child += 1;
+            }
+            if !self.is_greater(child, ix) {
+                return;
+            }
+            self.data.swap(ix, child);
+            ix = child;
+        }
+    }
+}
+
+impl<'a, T, S, C: Compare<T, S>> PeekMut<'a, T, S, C> {
+    /// Removes the peeked item and returns it.
+    pub fn pop_boxed(mut this: Self) -> Box<Handle<T, [S]>> {
+        this.sift = false;
+        this.heap.remove_top();
+        this.heap.data.pop_boxed()
+    }
+
+    /// Removes the peeked item as if the slice part was immediately dropped,
+    /// but returning the value.
+    pub fn pop_value(mut this: Self) -> T {
+        this.sift = false;
+        this.heap.remove_top();
+        this.heap.data.pop_value()
+    }
+}
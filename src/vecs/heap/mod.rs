@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+use crate::{
+    FixedVec,
+    Handle,
+};
+
+mod traits;
+mod implementation;
+
+/// Provides the ordering used by a [`BinaryHeap`]. The greatest item
+/// according to the ordering is the one at the top of the heap.
+///
+/// This is implemented for [`ValueOrder`] and any closure comparing two
+/// handles.
+pub trait Compare<T, S> {
+    fn compare(&self, left: &Handle<T, [S]>, right: &Handle<T, [S]>) -> Ordering;
+}
+
+/// Orders items by their `value`, ignoring the tail.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ValueOrder;
+
+/// A priority queue implemented with a binary heap, where each item is stored
+/// inline in a [`FixedVec`]. This is the [`std::collections::BinaryHeap`] to
+/// the [`FixedVec`] what that is to the [`std::vec::Vec`]; restructuring the
+/// heap moves entire items, including their tail.
+///
+/// By default, the item with the greatest `value` is at the top of the heap.
+/// A different ordering over the entire [`Handle`] may be provided with
+/// [`with_comparator`](BinaryHeap::with_comparator).
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::FixedBinaryHeap;
+///
+/// let mut heap = FixedBinaryHeap::<u32, &str>::new(1);
+/// heap.push(2, ["second"]);
+/// heap.push(7, ["first"]);
+/// heap.push(1, ["third"]);
+///
+/// assert_eq!(heap.peek().unwrap().tail, ["first"]);
+/// assert_eq!(heap.pop_value(), Some(7));
+/// assert_eq!(heap.pop_boxed().unwrap().tail, ["second"]);
+/// assert_eq!(heap.len(), 1);
+/// ```
+pub struct BinaryHeap<T, S, C = ValueOrder> {
+    data: FixedVec<T, S>,
+    compare: C,
+}
+
+/// Mutable access to the greatest item of a [`BinaryHeap`], returned by
+/// [`peek_mut`](BinaryHeap::peek_mut). When modified, the heap is
+/// restructured as this is dropped.
+pub struct PeekMut<'a, T, S, C: Compare<T, S>> {
+    heap: &'a mut BinaryHeap<T, S, C>,
+    sift: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ordering() {
+        let mut heap = BinaryHeap::<u32, u8>::new(2);
+        for value in [5, 1, 8, 3, 9, 2, 7].iter().copied() {
+            heap.push(value, [value as u8, 0]);
+        }
+        assert_eq!(heap.len(), 7);
+        assert_eq!(heap.peek().unwrap().value, 9);
+
+        let mut values = std::vec::Vec::new();
+        while let Some(handle) = heap.pop_boxed() {
+            assert_eq!(handle.tail, [handle.value as u8, 0]);
+            values.push(handle.value);
+        }
+        assert_eq!(values, [9, 8, 7, 5, 3, 2, 1]);
+    }
+
+    #[test]
+    fn comparator() {
+        let mut heap = BinaryHeap::with_comparator(
+            3,
+            |left: &Handle<(), [i32]>, right: &Handle<(), [i32]>| {
+                let left: i32 = left.tail.iter().sum();
+                let right: i32 = right.tail.iter().sum();
+                right.cmp(&left)
+            },
+        );
+        heap.push((), [1, 2, 3]);
+        heap.push((), [0, 0, 1]);
+        heap.push((), [4, 4, 4]);
+        assert_eq!(heap.peek().unwrap().tail, [0, 0, 1]);
+
+        let sorted = heap.into_sorted_vec();
+        let tails = sorted
+            .iter()
+            .map(|handle| handle.tail.to_vec())
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(tails, [[4, 4, 4], [1, 2, 3], [0, 0, 1]]);
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut heap = BinaryHeap::<u32, String>::new(1);
+        heap.push(3, vec!["a".to_string()]);
+        heap.push(2, vec!["b".to_string()]);
+        heap.push(1, vec!["c".to_string()]);
+
+        heap.peek_mut().unwrap().value = 0;
+        assert_eq!(heap.peek().unwrap().tail, ["b"]);
+
+        let top = heap.peek_mut().unwrap();
+        assert_eq!(PeekMut::pop_value(top), 2);
+        assert_eq!(heap.pop_value(), Some(1));
+        assert_eq!(heap.pop_value(), Some(0));
+        assert_eq!(heap.pop_value(), None);
+    }
+}
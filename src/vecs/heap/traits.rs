@@ -0,0 +1,93 @@
+use std::{
+    mem,
+    ops::{
+        Deref,
+        DerefMut,
+    },
+};
+use crate::{
+    FixedVec,
+    FixedVecIter,
+    Handle,
+    prelude::*,
+};
+use super::*;
+
+impl<T: Debug, S: Debug, C> Debug for BinaryHeap<T, S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_struct("BinaryHeap")
+            .field("values", &self.data.iter())
+            .finish()
+    }
+}
+
+impl<T: Copy, S: Copy, C: Clone> Clone for BinaryHeap<T, S, C> {
+    fn clone(&self) -> Self {
+        BinaryHeap {
+            data: self.data.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<T: Ord, S> From<FixedVec<T, S>> for BinaryHeap<T, S> {
+    #[inline(always)]
+    fn from(data: FixedVec<T, S>) -> Self {
+        BinaryHeap::from_vec_with_comparator(data, ValueOrder)
+    }
+}
+
+impl<T, S, C> From<BinaryHeap<T, S, C>> for FixedVec<T, S> {
+    #[inline(always)]
+    fn from(heap: BinaryHeap<T, S, C>) -> Self {
+        heap.data
+    }
+}
+
+impl<'a, T, S, C> IntoIterator for &'a BinaryHeap<T, S, C> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = FixedVecIter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T, S, C: Compare<T, S>> Deref for PeekMut<'_, T, S, C> {
+    type Target = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        // PeekMut is only constructed for a non-empty heap
+        unsafe { self.heap.data.get_unchecked(0) }
+    }
+}
+
+impl<T, S, C: Compare<T, S>> DerefMut for PeekMut<'_, T, S, C> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.sift = true;
+        // PeekMut is only constructed for a non-empty heap
+        unsafe { self.heap.data.get_unchecked_mut(0) }
+    }
+}
+
+impl<T: Debug, S: Debug, C: Compare<T, S>> Debug for PeekMut<'_, T, S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_tuple("PeekMut")
+            .field(&&**self)
+            .finish()
+    }
+}
+
+impl<T, S, C: Compare<T, S>> Drop for PeekMut<'_, T, S, C> {
+    fn drop(&mut self) {
+        if mem::replace(&mut self.sift, false) {
+            let end = self.heap.len();
+            self.heap.sift_down(0, end);
+        }
+    }
+}
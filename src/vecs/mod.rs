@@ -1 +1,7 @@
+pub mod array;
+pub mod double;
 pub mod fixed;
+pub mod heap;
+pub mod segmented;
+pub mod small;
+pub mod tail;
@@ -0,0 +1,296 @@
+use std::{
+    alloc::Layout,
+    cell::Cell,
+    num::NonZeroUsize,
+    pin::Pin,
+    ptr::{
+        drop_in_place,
+        NonNull,
+        read,
+    },
+};
+use crate::{
+    alloc::alloc_layout,
+    Handle,
+    util::{
+        can_try_alloc,
+        CopyNonoverlappingFn,
+        DefaultIter,
+        PtrCopy,
+    },
+};
+use super::*;
+
+/// Resets the push flag, even when populating an item panics.
+struct PushGuard<'a>(&'a Cell<bool>);
+
+impl Drop for PushGuard<'_> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+impl<T, S> Vec<T, S> {
+    /// Creates a new [`Vec`] that can contain items where the tail length is
+    /// as provided. Will not allocate until an item is inserted.
+    pub fn new(slice_length: usize) -> Self {
+        let size = Handle::<T, [S]>::size_slice(slice_length);
+
+        Vec {
+            segments: [(); SEGMENTS].map(|_| Cell::new(None)),
+            length: Cell::new(0),
+            pushing: Cell::new(false),
+            slice: slice_length,
+            // Same minimum as the std::vec::Vec
+            first: if size == 1 {
+                8
+            } else if size <= 1024 {
+                4
+            } else {
+                1
+            },
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length.get()
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length.get() == 0
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.slice
+    }
+
+    /// Returns the maximum number of items before another segment is needed.
+    pub fn capacity(&self) -> usize {
+        let segments = self
+            .segments
+            .iter()
+            .take_while(|segment| segment.get().is_some())
+            .count();
+        // Sum of the geometric series
+        self.first * ((1 << segments) - 1)
+    }
+
+    #[inline(always)]
+    pub(super) fn segment_capacity(&self, segment: usize) -> NonZeroUsize {
+        NonZeroUsize::new(
+            self.first
+                .checked_shl(segment as u32)
+                .filter(|&capacity| capacity >> segment == self.first)
+                .expect("Overflow")
+        ).expect("Overflow")
+    }
+
+    /// Returns the segment and the index inside of it.
+    #[inline(always)]
+    fn locate(&self, ix: usize) -> (usize, usize) {
+        // Segment N starts at index first * (2^N - 1)
+        let bucket = ix / self.first + 1;
+        let segment = (usize::BITS - 1 - bucket.leading_zeros()) as usize;
+        (segment, ix - self.first * ((1 << segment) - 1))
+    }
+
+    #[inline(always)]
+    fn ptr(&self, ix: usize) -> *mut Handle<T, [S]> {
+        let (segment, offset) = self.locate(ix);
+        let ptr = match self.segments[segment].get() {
+            Some(ptr) => ptr,
+            // Only called on indexes that have been allocated
+            None => unsafe { std::hint::unreachable_unchecked() },
+        };
+        unsafe { get_ix!(T S, ptr, self.slice, offset,) }
+    }
+
+    fn alloc_segment(&self, segment: usize) -> NonNull<u8> {
+        let layout = Handle::<T, [S]>::layout_slice(self.slice, self.segment_capacity(segment));
+        if !can_try_alloc(layout.size()) {
+            panic!("Overflow");
+        }
+        let ptr = alloc_layout(layout);
+        self.segments[segment].set(Some(ptr));
+        ptr
+    }
+
+    fn do_push(&self, value: T, slice: impl IntoIterator<Item=S>) -> *mut Handle<T, [S]> {
+        if self.pushing.replace(true) {
+            panic!("Reentrant push");
+        }
+        let _guard = PushGuard(&self.pushing);
+
+        let length = self.length.get();
+        let (segment, offset) = self.locate(length);
+        let ptr = match self.segments[segment].get() {
+            Some(ptr) => ptr,
+            None => self.alloc_segment(segment),
+        };
+        let handle = unsafe { get_ix!(T S, ptr, self.slice, offset,) };
+        let mut slice = slice.into_iter();
+        Handle::populate(handle, self.slice..(self.slice + 1), value, &mut slice);
+
+        // This puts it in the drop
+        self.length.set(length + 1);
+
+        handle
+    }
+
+    /// Adds an item using an iterator containing at least enough values to
+    /// populate the DST's slice, and returns a reference to it. No other
+    /// item is moved, so references to other items remain valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count. Panics if the
+    /// iterator pushes into this [`Vec`].
+    #[inline(always)]
+    pub fn push(&self, value: T, slice: impl IntoIterator<Item=S>) -> &Handle<T, [S]> {
+        unsafe { &*self.do_push(value, slice) }
+    }
+
+    /// Removes the last inserted element as if it was immediately dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop(&mut self) {
+        let length = self.length.get();
+        if length == 0 {
+            panic!("No value to remove");
+        }
+        self.length.set(length - 1);
+        unsafe { drop_in_place(self.ptr(length - 1)) }
+    }
+
+    /// Returns a reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&Handle<T, [S]>> {
+        if ix >= self.length.get() {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked(ix) })
+        }
+    }
+
+    /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, [S]> {
+        &*self.ptr(ix)
+    }
+
+    /// Returns a pinned mutable reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_pin_mut(&mut self, ix: usize) -> Option<Pin<&mut Handle<T, [S]>>> {
+        if ix >= self.length.get() {
+            None
+        } else {
+            // Items are never moved unless they are Unpin
+            Some(unsafe { Pin::new_unchecked(&mut *self.ptr(ix)) })
+        }
+    }
+
+    /// Returns an iterator that provides references.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, S> {
+        self.into_iter()
+    }
+}
+
+impl<T: Unpin, S: Unpin> Vec<T, S> {
+    /// Returns a mutable reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut Handle<T, [S]>> {
+        if ix >= self.length.get() {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked_mut(ix) })
+        }
+    }
+
+    /// Returns a mutable reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, [S]> {
+        &mut *self.ptr(ix)
+    }
+
+    /// Returns an iterator that provides mutable references.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, S> {
+        self.into_iter()
+    }
+
+    /// Removes the last inserted element and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop_boxed(&mut self) -> Box<Handle<T, [S]>> {
+        let length = self.length.get();
+        if length == 0 {
+            panic!("No value to remove");
+        }
+        self.length.set(length - 1);
+        unsafe {
+            let handle = self.ptr(length - 1);
+            let layout = Layout::for_value(&*handle);
+
+            let target = alloc_layout(layout);
+            CopyNonoverlappingFn::copy(handle as *const u8, target.as_ptr(), layout.size());
+            Box::from_raw(get_ix!(T S, target, self.slice, 0,))
+        }
+    }
+
+    /// Removes the last inserted element as if the slice part was immediately
+    /// dropped, but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop_value(&mut self) -> T {
+        let length = self.length.get();
+        if length == 0 {
+            panic!("No value to remove");
+        }
+        self.length.set(length - 1);
+
+        let handle = self.ptr(length - 1);
+        unsafe {
+            drop_in_place(Handle::tail_ptr(handle));
+            read(Handle::value_ptr(handle))
+        }
+    }
+}
+
+impl<T, S: Default> Vec<T, S> {
+    /// Appends at the end using default to populate the slice.
+    #[inline(always)]
+    pub fn push_default_slice(&self, value: T) -> &Handle<T, [S]> {
+        self.push(value, DefaultIter::default())
+    }
+}
+
+impl<T: Default, S: Default> Vec<T, S> {
+    /// Appends at the end using default to populate the value and slice.
+    #[inline(always)]
+    pub fn push_default(&self) -> &Handle<T, [S]> {
+        self.push(T::default(), DefaultIter::default())
+    }
+}
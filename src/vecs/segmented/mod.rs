@@ -0,0 +1,145 @@
+use std::{
+    cell::Cell,
+    marker::PhantomData,
+    ops::Range,
+    ptr::NonNull,
+};
+use crate::Handle;
+
+mod traits;
+mod implementation;
+
+/// One segment for each bit of the index, which is more than can ever be
+/// allocated.
+const SEGMENTS: usize = usize::BITS as usize;
+
+/// A [`FixedVec`](crate::FixedVec) that never moves its items. Instead of
+/// reallocating, it allocates segments of geometrically growing capacity:
+/// the first segment fits a small number of items, and each later segment
+/// fits double the previous. Thus, a reference or pointer to an item stays
+/// valid for as long as the item is in the [`Vec`], regardless of how many
+/// items are pushed afterwards.
+///
+/// Because pushing never moves an item, [`push`](Vec::push) only needs a
+/// shared reference. Likewise, items may be accessed through [`Pin`]
+/// (see [`get_pin_mut`](Vec::get_pin_mut)), and any operation that would
+/// move an item out requires the item to be [`Unpin`].
+///
+/// The trade-off is that the items are no longer in one contiguous
+/// allocation, and that random-access requires computing the segment.
+///
+/// [`Pin`]: std::pin::Pin
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::SegmentedFixedVec;
+///
+/// let vec = SegmentedFixedVec::<u32, u8>::new(2);
+/// let first = vec.push(1, [1, 2]);
+/// for ix in 2..100 {
+///     vec.push(ix, [0, 0]);
+/// }
+/// assert_eq!(first.value, 1);
+/// assert_eq!(first.tail, [1, 2]);
+/// assert!(std::ptr::eq(first, vec.get(0).unwrap()));
+/// ```
+pub struct Vec<T, S> {
+    segments: [Cell<Option<NonNull<u8>>>; SEGMENTS],
+    length: Cell<usize>,
+    pushing: Cell<bool>,
+    slice: usize,
+    first: usize,
+    _phantom: PhantomData<Handle<T, [S]>>,
+}
+
+/// Iterator over references of the items of a [`Vec`].
+pub struct Iter<'a, T, S> {
+    iter: Range<usize>,
+    ptr: &'a Vec<T, S>,
+}
+
+/// Iterator over mutable references of the items of a [`Vec`].
+pub struct IterMut<'a, T, S> {
+    iter: Range<usize>,
+    ptr: &'a mut Vec<T, S>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        marker::PhantomPinned,
+        pin::Pin,
+        rc::Rc,
+    };
+    use super::*;
+
+    #[test]
+    fn stable() {
+        let vec = Vec::<usize, String>::new(2);
+        let mut pointers = std::vec::Vec::new();
+        for ix in 0..200 {
+            let handle = vec.push(ix, vec![ix.to_string(), "-".to_string()]);
+            pointers.push(handle as *const Handle<usize, [String]>);
+        }
+        assert_eq!(vec.len(), 200);
+        for (ix, pointer) in pointers.into_iter().enumerate() {
+            let handle = vec.get(ix).unwrap();
+            assert!(std::ptr::eq(pointer, handle));
+            assert_eq!(handle.value, ix);
+            assert_eq!(handle.tail, [ix.to_string(), "-".to_string()]);
+        }
+        assert_eq!(vec.iter().count(), 200);
+        assert!(vec.capacity() >= 200);
+    }
+
+    #[test]
+    fn drops() {
+        let counter = Rc::new(());
+        let mut vec = Vec::<Rc<()>, Rc<()>>::new(3);
+        for _ in 0..20 {
+            vec.push(counter.clone(), std::iter::repeat(counter.clone()));
+        }
+        assert_eq!(Rc::strong_count(&counter), 81);
+        vec.pop();
+        assert_eq!(Rc::strong_count(&counter), 77);
+        let value = vec.pop_value();
+        assert_eq!(Rc::strong_count(&counter), 74);
+        drop(value);
+        let boxed = vec.pop_boxed();
+        assert_eq!(boxed.tail.len(), 3);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 5);
+        drop(boxed);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reentrant push")]
+    fn reentrant() {
+        let vec = Vec::<(), u8>::new(1);
+        vec.push((), std::iter::from_fn(|| {
+            vec.push((), [0]);
+            Some(1)
+        }));
+    }
+
+    #[test]
+    fn pinned() {
+        let mut vec = Vec::<PhantomPinned, u8>::new(1);
+        vec.push(PhantomPinned, [1]);
+        let handle: Pin<&mut Handle<PhantomPinned, [u8]>> = vec.get_pin_mut(0).unwrap();
+        unsafe { handle.get_unchecked_mut() }.tail[0] = 2;
+        assert_eq!(vec[(0, 0)], 2);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = Vec::<(), ()>::new(3);
+        for _ in 0..100 {
+            vec.push_default();
+        }
+        assert_eq!(vec.iter().count(), 100);
+        assert_eq!(vec.pop_boxed().tail.len(), 3);
+    }
+}
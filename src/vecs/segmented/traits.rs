@@ -0,0 +1,178 @@
+use std::ops::{
+    Index,
+    IndexMut,
+};
+use crate::{
+    alloc::dealloc_layout,
+    Handle,
+    prelude::*,
+    util::{
+        cell_out_of_bounds,
+        index_out_of_bounds,
+    },
+};
+use super::*;
+
+unsafe impl<T, S> Send for Vec<T, S> where Handle<T, [S]>: Send {}
+
+impl<TL: PartialEq<TR>, TR, SL: PartialEq<SR>, SR> PartialEq<Vec<TR, SR>> for Vec<TL, SL> {
+    fn eq(&self, other: &Vec<TR, SR>) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(|(left, right)| left == right)
+    }
+}
+
+impl<T: Eq, S: Eq> Eq for Vec<T, S> {}
+
+impl<T: Hash, S: Hash> Hash for Vec<T, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: Debug, S: Debug> Debug for Vec<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_struct("Vec")
+            .field("values", &self.iter())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl<T: Debug, S: Debug> Debug for Iter<'_, T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_list()
+            .entries(self.clone())
+            .finish()
+    }
+}
+
+impl<'a, T, S> Clone for Iter<'a, T, S> {
+    fn clone(&self) -> Self {
+        Iter {
+            iter: self.iter.clone(),
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<'a, T, S> Iterator for Iter<'a, T, S> {
+    type Item = &'a Handle<T, [S]>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe { self.ptr.get_unchecked(ix) })
+    }
+}
+
+impl<'a, T: Unpin, S: Unpin> Iterator for IterMut<'a, T, S> {
+    type Item = &'a mut Handle<T, [S]>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            .map(|ix| unsafe {
+                // Cheating the lifetime
+                &mut *(self.ptr.get_unchecked_mut(ix) as *mut _)
+            })
+    }
+}
+
+impl<'a, T, S> IntoIterator for &'a Vec<T, S> {
+    type Item = &'a Handle<T, [S]>;
+    type IntoIter = Iter<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            iter: 0..(self.len()),
+            ptr: self,
+        }
+    }
+}
+
+impl<'a, T: Unpin, S: Unpin> IntoIterator for &'a mut Vec<T, S> {
+    type Item = &'a mut Handle<T, [S]>;
+    type IntoIter = IterMut<'a, T, S>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            iter: 0..(self.len()),
+            ptr: self,
+        }
+    }
+}
+
+impl<T, S> Index<usize> for Vec<T, S> {
+    type Output = Handle<T, [S]>;
+
+    #[inline(always)]
+    fn index(&self, ix: usize) -> &Self::Output {
+        match self.get(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, self.len(), self.slice),
+        }
+    }
+}
+
+impl<T: Unpin, S: Unpin> IndexMut<usize> for Vec<T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, ix: usize) -> &mut Self::Output {
+        let (length, slice) = (self.len(), self.slice);
+        match self.get_mut(ix) {
+            Some(handle) => handle,
+            None => index_out_of_bounds(ix, length, slice),
+        }
+    }
+}
+
+impl<T, S> Index<(usize, usize)> for Vec<T, S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, (ix, column): (usize, usize)) -> &Self::Output {
+        match self.get(ix).and_then(|handle| handle.tail.get(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, self.len(), self.slice),
+        }
+    }
+}
+
+impl<T: Unpin, S: Unpin> IndexMut<(usize, usize)> for Vec<T, S> {
+    #[inline(always)]
+    fn index_mut(&mut self, (ix, column): (usize, usize)) -> &mut Self::Output {
+        let (length, slice) = (self.len(), self.slice);
+        match self.get_mut(ix).and_then(|handle| handle.tail.get_mut(column)) {
+            Some(cell) => cell,
+            None => cell_out_of_bounds(ix, column, length, slice),
+        }
+    }
+}
+
+impl<T, S> Drop for Vec<T, S> {
+    fn drop(&mut self) {
+        while !self.is_empty() {
+            self.pop()
+        }
+        for (segment, ptr) in self.segments.iter().enumerate() {
+            if let Some(ptr) = ptr.take() {
+                unsafe { dealloc_layout(
+                    ptr,
+                    Handle::<T, [S]>::layout_slice(self.slice, self.segment_capacity(segment)),
+                ) }
+            } else {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    mem::{
+        self,
+        ManuallyDrop,
+    },
+    ops::RangeBounds,
+    ptr::{
+        copy_nonoverlapping,
+        read,
+    },
+};
+use crate::{
+    vecs::array::Fixed,
+    FixedVec,
+    FixedVecIter,
+    FixedVecIterMut,
+    FixedView,
+    FixedViewMut,
+    Handle,
+    util::DefaultIter,
+};
+use super::*;
+
+impl<T, S, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Creates a new [`Vec`] that can contain items where the tail length is
+    /// as provided. Will not allocate until more items are inserted than fit
+    /// inline.
+    pub fn new(slice_length: usize) -> Self {
+        Vec {
+            data: Data::Inline(ArrayFixedVec::new(slice_length)),
+            slice: slice_length,
+        }
+    }
+
+    /// Returns the maximum number of items before a reallocation is needed.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        match &self.data {
+            Data::Inline(array) => array.capacity(),
+            Data::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    /// Returns the length of the tail for any/all items.
+    #[inline(always)]
+    pub fn slice_length(&self) -> usize {
+        self.slice
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        match &self.data {
+            Data::Inline(array) => array.len(),
+            Data::Heap(vec) => vec.len(),
+        }
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Checks if the items have been moved to a heap allocation.
+    #[inline(always)]
+    pub fn spilled(&self) -> bool {
+        matches!(self.data, Data::Heap(_))
+    }
+
+    pub(super) fn fixed(&mut self) -> Fixed<'_, T, S> {
+        match &mut self.data {
+            Data::Inline(array) => array.fixed(),
+            Data::Heap(vec) => Fixed::Heap(vec),
+        }
+    }
+
+    /// Moves the items into a heap allocation with exactly the specified
+    /// capacity.
+    fn spill(&mut self, capacity: usize) {
+        let mut vec = FixedVec::new(self.slice);
+        vec.reserve_exact(capacity);
+        if let Data::Inline(array) = &mut self.data {
+            unsafe {
+                copy_nonoverlapping(
+                    array.buffer.ptr().as_ptr(),
+                    vec.as_mut_ptr() as *mut u8,
+                    array.length * vec.item_size(),
+                );
+                vec.set_len(mem::replace(&mut array.length, 0));
+            }
+        }
+        self.data = Data::Heap(vec);
+    }
+
+    /// Will insure it has enough space for the specified number of items,
+    /// spilling into a heap allocation if they do not fit inline.
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.data {
+            Data::Inline(array) => {
+                let total = array.len().checked_add(additional).expect("Overflow");
+                let capacity = array.capacity();
+                if total > capacity {
+                    self.spill(total.max(2 * capacity));
+                }
+            },
+            Data::Heap(vec) => vec.reserve(additional),
+        }
+    }
+
+    /// Will insure it has enough space for the specified number of items,
+    /// allocating exactly enough if they do not fit.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        match &mut self.data {
+            Data::Inline(array) => {
+                let total = array.len().checked_add(additional).expect("Overflow");
+                if total > array.capacity() {
+                    self.spill(total);
+                }
+            },
+            Data::Heap(vec) => vec.reserve_exact(additional),
+        }
+    }
+
+    /// Adds an item using an iterator containing at least enough values to
+    /// populate the DST's slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count.
+    #[inline(always)]
+    pub fn push(&mut self, value: T, slice: impl IntoIterator<Item=S>) {
+        self.reserve(1);
+        self.fixed().push(value, slice)
+    }
+
+    /// Inserts an item using an iterator containing at least enough values to
+    /// populate the DST's slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count. Panics if any
+    /// index lower than the one provided has no item.
+    #[inline(always)]
+    pub fn insert(&mut self, ix: usize, value: T, slice: impl IntoIterator<Item=S>) {
+        if ix > self.len() {
+            panic!("Out of bounds insert");
+        }
+        self.reserve(1);
+        self.fixed().insert(ix, value, slice)
+    }
+
+    /// Removes the last inserted element as if it was immediately dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    #[inline(always)]
+    pub fn pop(&mut self) {
+        self.fixed().pop()
+    }
+
+    /// Removes the last inserted element and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    #[inline(always)]
+    pub fn pop_boxed(&mut self) -> Box<Handle<T, [S]>> {
+        self.fixed().pop_boxed()
+    }
+
+    /// Removes the last inserted element as if the slice part was immediately
+    /// dropped, but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    #[inline(always)]
+    pub fn pop_value(&mut self) -> T {
+        self.fixed().pop_value()
+    }
+
+    /// Returns a reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&Handle<T, [S]>> {
+        self.as_view().get(ix)
+    }
+
+    /// Returns a reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, [S]> {
+        self.as_view().get_unchecked(ix)
+    }
+
+    /// Returns a mutable reference, or `None` if out of bounds.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut Handle<T, [S]>> {
+        if ix >= self.len() {
+            None
+        } else {
+            Some(unsafe { self.get_unchecked_mut(ix) })
+        }
+    }
+
+    /// Returns a mutable reference without bound-checking.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, [S]> {
+        let view = self.as_view_mut();
+        &mut *get_ix!(T S, view.ptr, view.slice, ix,)
+    }
+
+    /// Swaps the two items in place, moving the entire value and tail of
+    /// each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at either index.
+    #[inline(always)]
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.fixed().swap(a, b)
+    }
+
+    /// Returns an iterator that provides references.
+    #[inline(always)]
+    pub fn iter(&self) -> FixedVecIter<'_, T, S> {
+        self.as_view().into_iter()
+    }
+
+    /// Returns an iterator that provides mutable references.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> FixedVecIterMut<'_, T, S> {
+        self.as_view_mut().into_iter()
+    }
+
+    /// Returns a view of all items.
+    pub fn as_view(&self) -> FixedView<'_, T, S> {
+        match &self.data {
+            Data::Inline(array) => array.as_view(),
+            Data::Heap(vec) => vec.as_view(),
+        }
+    }
+
+    /// Returns a mutable view of all items.
+    pub fn as_view_mut(&mut self) -> FixedViewMut<'_, T, S> {
+        match &mut self.data {
+            Data::Inline(array) => array.as_view_mut(),
+            Data::Heap(vec) => vec.as_view_mut(),
+        }
+    }
+
+    /// Returns a view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn view(&self, range: impl RangeBounds<usize>) -> FixedView<'_, T, S> {
+        self.as_view().view(range)
+    }
+
+    /// Returns a mutable view of the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    pub fn view_mut(&mut self, range: impl RangeBounds<usize>) -> FixedViewMut<'_, T, S> {
+        let FixedView { ptr, length, slice, .. } = self.view(range);
+        FixedViewMut {
+            ptr,
+            length,
+            slice,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Items the item at the index, shifting any later items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove(&mut self, ix: usize) {
+        self.fixed().remove(ix)
+    }
+
+    /// Removes the range of items, shifting any later items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn remove_range(&mut self, range: impl RangeBounds<usize>) {
+        self.fixed().remove_range(range)
+    }
+
+    /// Removes the item at the index, replacing it with the last item.
+    /// This avoids excessive copies, but by reordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove_replace(&mut self, ix: usize) {
+        self.fixed().remove_replace(ix)
+    }
+
+    /// Removes the range of items, replacing them with items from the end.
+    /// This avoids excessive copies, but by reordering.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index as specified by a non-open bound in the range has
+    /// no item.
+    #[inline(always)]
+    pub fn remove_range_replace(&mut self, range: impl RangeBounds<usize>) {
+        self.fixed().remove_range_replace(range)
+    }
+
+    /// Removes the item at index as if the slice part was immediately dropped,
+    /// but returning the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at the index.
+    #[inline(always)]
+    pub fn remove_value(&mut self, ix: usize) -> T {
+        self.fixed().remove_value(ix)
+    }
+
+    /// Converts into a [`FixedVec`]. When already spilled, the allocation is
+    /// reused without copying any items.
+    pub fn into_fixed_vec(mut self) -> FixedVec<T, S> {
+        if let Data::Inline(array) = &self.data {
+            self.spill(array.len());
+        }
+        let this = ManuallyDrop::new(self);
+        // The data is moved out, and the rest is never dropped
+        match unsafe { read(&this.data) } {
+            Data::Heap(vec) => vec,
+            // Spilled above
+            Data::Inline(_) => unsafe { std::hint::unreachable_unchecked() },
+        }
+    }
+}
+
+impl<T, S: Default, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Appends at the end using default to populate the slice.
+    #[inline(always)]
+    pub fn push_default_slice(&mut self, value: T) -> &mut Handle<T, [S]> {
+        self.push(value, DefaultIter::default());
+        let ix = self.len() - 1;
+        unsafe { self.get_unchecked_mut(ix) }
+    }
+
+    /// Inserts at the index using default to populate the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index lower than the one provided has no item.
+    #[inline(always)]
+    pub fn insert_default_slice(&mut self, ix: usize, value: T) -> &mut Handle<T, [S]> {
+        self.insert(ix, value, DefaultIter::default());
+        unsafe { self.get_unchecked_mut(ix) }
+    }
+}
+
+impl<T: Default, S: Default, const BYTES: usize> Vec<T, S, BYTES> {
+    /// Appends at the end using default to populate the value and slice.
+    #[inline(always)]
+    pub fn push_default(&mut self) -> &mut Handle<T, [S]> {
+        self.push_default_slice(T::default())
+    }
+
+    /// Inserts at the index using default to populate the value and slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index lower than the one provided has no item.
+    #[inline(always)]
+    pub fn insert_default(&mut self, ix: usize) -> &mut Handle<T, [S]> {
+        self.insert_default_slice(ix, T::default())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    ArrayFixedVec,
+    FixedVec,
+};
+
+mod traits;
+mod implementation;
+
+/// A [`FixedVec`] that stores its items inline, in a buffer of `BYTES`
+/// bytes, until they no longer fit. Then, the items are moved into the same
+/// heap allocation that a [`FixedVec`] uses, and the [`Vec`] is said to have
+/// *spilled*. A spilled [`Vec`] converts into a [`FixedVec`] without copying
+/// any items.
+///
+/// Thus, a few items can be stored without any allocation, at the expense of
+/// the size of the [`Vec`] itself. The number of items that fit inline is
+/// `BYTES` divided by the size of an item, which depends on the tail length.
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::SmallFixedVec;
+///
+/// let mut vec = SmallFixedVec::<u16, u16, 16>::new(3);
+/// assert_eq!(vec.capacity(), 2);
+///
+/// vec.push(1, [1, 2, 3]);
+/// vec.push(2, [4, 5, 6]);
+/// assert!(!vec.spilled());
+///
+/// vec.push(3, [7, 8, 9]);
+/// assert!(vec.spilled());
+/// assert_eq!(vec[0].tail, [1, 2, 3]);
+/// assert_eq!(vec[(2, 2)], 9);
+/// ```
+pub struct Vec<T, S, const BYTES: usize> {
+    data: Data<T, S, BYTES>,
+    slice: usize,
+}
+
+enum Data<T, S, const BYTES: usize> {
+    Inline(ArrayFixedVec<T, S, BYTES>),
+    Heap(FixedVec<T, S>),
+}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+    use crate::Handle;
+    use super::*;
+
+    #[test]
+    fn spill() {
+        let mut vec = Vec::<u8, u32, 32>::new(2);
+        assert_eq!(vec.capacity(), 2);
+        vec.push(0, [0, 1]);
+        vec.insert(0, 1, [2, 3]);
+        assert!(!vec.spilled());
+
+        vec.push(2, [4, 5]);
+        assert!(vec.spilled());
+        assert!(vec.capacity() >= 3);
+        let values = vec
+            .iter()
+            .map(|handle| (handle.value, handle.tail.to_vec()))
+            .collect::<std::vec::Vec<_>>();
+        assert_eq!(values, [(1, vec![2, 3]), (0, vec![0, 1]), (2, vec![4, 5])]);
+
+        let first = vec.get(0).unwrap() as *const Handle<u8, [u32]>;
+        let vec = vec.into_fixed_vec();
+        assert!(std::ptr::eq(first, vec.get(0).unwrap()));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut small = Vec::<u8, u8, 16>::new(2);
+        let mut spilled = Vec::<u8, u8, 16>::new(2);
+        for ix in 0..3 {
+            small.push(ix, [ix; 2]);
+            spilled.push(ix, [ix; 2]);
+        }
+        for ix in 3..8 {
+            spilled.push(ix, [ix; 2]);
+        }
+        assert!(!small.spilled());
+        assert!(spilled.spilled());
+        assert!(small < spilled);
+        spilled[(1, 0)] = 0;
+        assert!(small > spilled);
+        assert_eq!(small.cmp(&small.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn inline() {
+        let mut vec = Vec::<u8, u8, 16>::new(3);
+        for ix in 0..4 {
+            vec.push(ix, [ix; 3]);
+        }
+        vec.remove(1);
+        vec[(0, 1)] = 9;
+        assert_eq!(vec.pop_value(), 3);
+        assert!(!vec.spilled());
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec[0].tail, [0, 9, 0]);
+        assert_eq!(vec[1].tail, [2, 2, 2]);
+
+        let fixed = vec.clone().into_fixed_vec();
+        assert_eq!(fixed, vec);
+        assert_eq!(Vec::<u8, u8, 16>::from(fixed), vec);
+    }
+
+    #[test]
+    fn drops() {
+        let counter = Rc::new(());
+        let mut vec = Vec::<Rc<()>, Rc<()>, 64>::new(1);
+        vec.push(counter.clone(), [counter.clone()]);
+        vec.push(counter.clone(), [counter.clone()]);
+        assert!(!vec.spilled());
+        assert_eq!(Rc::strong_count(&counter), 5);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let mut vec = Vec::<Rc<()>, Rc<()>, 64>::new(1);
+        for _ in 0..8 {
+            vec.push(counter.clone(), [counter.clone()]);
+        }
+        assert!(vec.spilled());
+        assert_eq!(Rc::strong_count(&counter), 17);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = Vec::<(), (), 0>::new(2);
+        for _ in 0..100 {
+            vec.push((), [(), ()]);
+        }
+        assert!(!vec.spilled());
+        assert_eq!(vec.len(), 100);
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    alloc::Layout,
+    mem::MaybeUninit,
+    num::NonZeroUsize,
+    ptr::{
+        drop_in_place,
+        slice_from_raw_parts_mut,
+        swap_nonoverlapping,
+        write,
+        NonNull,
+    },
+};
+use crate::{
+    alloc::{
+        alloc_capacity,
+        alloc_layout,
+        array_layout,
+        dealloc_layout,
+        empty_alloc,
+        reserve_alloc,
+        reserve_exact_alloc,
+    },
+    util::index_out_of_bounds,
+    CloneTail,
+    Dst,
+};
+use super::*;
+
+impl<T, D: ?Sized + DstTail> Vec<T, D> {
+    /// Creates a new [`Vec`] that can contain items with tails described by
+    /// the metadata. Will not allocate until an item is inserted or capacity
+    /// reserved, and never allocates for zero-sized items.
+    pub fn new(metadata: D::Metadata) -> Self {
+        Vec {
+            ptr: empty_alloc(D::layout::<T>(metadata)),
+            length: 0,
+            metadata,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Returns the metadata of the tails of any/all items.
+    #[inline(always)]
+    pub fn metadata(&self) -> D::Metadata {
+        self.metadata
+    }
+
+    /// Returns the maximum number of items before a reallocation is needed.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        alloc_capacity(&self.ptr)
+    }
+
+    /// Returns the number of items.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Checks if there are any items.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Will insure it has enough space for the specified number of items,
+    /// growing according to an internal criteria.
+    pub fn reserve(&mut self, additional: usize) {
+        reserve_alloc(&mut self.ptr, D::layout::<T>(self.metadata), self.length, additional)
+    }
+
+    /// Will allocate exactly enough memory to insure it has enough space for
+    /// the specified number of elements, or do nothing if it has already
+    /// allocated enough.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        reserve_exact_alloc(&mut self.ptr, D::layout::<T>(self.metadata), self.length, additional)
+    }
+
+    #[inline(always)]
+    fn item_size(&self) -> usize {
+        D::layout::<T>(self.metadata).size()
+    }
+
+    pub(super) fn layout(&self, count: NonZeroUsize) -> Layout {
+        array_layout(D::layout::<T>(self.metadata), count)
+    }
+
+    /// Returns a pointer to the item at the index.
+    ///
+    /// # Safety
+    ///
+    /// There must be an allocation with space for the index.
+    #[inline(always)]
+    unsafe fn item(&self, ix: usize) -> *mut Handle<T, D> {
+        let ptr = match self.ptr {
+            Some((ptr, _)) => ptr.as_ptr().add(ix * self.item_size()),
+            None => std::hint::unreachable_unchecked(),
+        };
+        D::handle_ptr(ptr, self.metadata)
+    }
+
+    #[inline(always)]
+    fn check_tail(&self, tail: &D) {
+        if tail.metadata() != self.metadata {
+            panic!("Mismatched tail");
+        }
+    }
+
+    #[inline(always)]
+    fn check_insert(&self, ix: usize) {
+        if ix > self.length {
+            panic!("Out of bounds insert");
+        }
+    }
+
+    /// Moves the item after the last one to the index, shifting every item
+    /// from the index onward. The moved item is not put in the drop.
+    ///
+    /// # Safety
+    ///
+    /// The item after the last one must be allocated and initialized, and the
+    /// index must not be greater than the number of items.
+    unsafe fn shift_in(&mut self, ix: usize) {
+        let size = self.item_size();
+        let start = self.item(ix) as *mut MaybeUninit<u8>;
+        let bytes = &mut *slice_from_raw_parts_mut(start, (self.length + 1 - ix) * size);
+        bytes.rotate_right(size);
+    }
+
+    /// Moves the boxed item to after the last one, which is not put in the
+    /// drop.
+    ///
+    /// # Safety
+    ///
+    /// The item after the last one must be allocated, and the tail must have
+    /// the same metadata.
+    unsafe fn write_boxed(&mut self, item: Box<Handle<T, D>>) {
+        let layout = Layout::for_value(&*item);
+        let source = Box::into_raw(item);
+        (source as *const u8).copy_to_nonoverlapping(self.item(self.length) as *mut u8, layout.size());
+        dealloc_layout(NonNull::new_unchecked(source as *mut u8), layout);
+    }
+
+    /// Adds an item, moving it out of the box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tail has different metadata.
+    pub fn push_boxed(&mut self, item: Box<Handle<T, D>>) {
+        self.check_tail(&item.tail);
+        self.reserve(1);
+        unsafe { self.write_boxed(item) };
+        self.length += 1;
+    }
+
+    /// Inserts an item, moving it out of the box.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tail has different metadata. Panics if any index lower
+    /// than the one provided has no item.
+    pub fn insert_boxed(&mut self, ix: usize, item: Box<Handle<T, D>>) {
+        self.check_insert(ix);
+        self.check_tail(&item.tail);
+        self.reserve(1);
+        unsafe {
+            self.write_boxed(item);
+            self.shift_in(ix);
+        }
+        self.length += 1;
+    }
+
+    /// Adds an item, letting the closure populate the tail in place. The
+    /// value is written after the closure returns, so it is dropped if the
+    /// closure panics.
+    ///
+    /// # Safety
+    ///
+    /// The closure must fully initialize the tail behind the pointer, which
+    /// is described by the [`metadata`](Self::metadata), or panic without
+    /// leaving any of it to be dropped.
+    pub unsafe fn push_with(&mut self, value: T, init: impl FnOnce(*mut D)) {
+        self.reserve(1);
+        let handle = self.item(self.length);
+        init(Handle::tail_ptr(handle));
+        write(Handle::value_ptr(handle), value);
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Inserts an item, letting the closure populate the tail in place. The
+    /// value is written after the closure returns, so it is dropped if the
+    /// closure panics.
+    ///
+    /// # Safety
+    ///
+    /// The closure must fully initialize the tail behind the pointer, which
+    /// is described by the [`metadata`](Self::metadata), or panic without
+    /// leaving any of it to be dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index lower than the one provided has no item.
+    pub unsafe fn insert_with(&mut self, ix: usize, value: T, init: impl FnOnce(*mut D)) {
+        self.check_insert(ix);
+        self.reserve(1);
+        // Constructed after the end, so that nothing moves until it succeeds
+        let handle = self.item(self.length);
+        init(Handle::tail_ptr(handle));
+        write(Handle::value_ptr(handle), value);
+        self.shift_in(ix);
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Removes the last inserted element as if it was immediately dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop(&mut self) {
+        if self.length == 0 {
+            panic!("No value to remove");
+        }
+        self.length -= 1;
+        unsafe { drop_in_place(self.item(self.length)) }
+    }
+
+    /// Removes the last inserted element and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no items.
+    pub fn pop_boxed(&mut self) -> Box<Handle<T, D>> {
+        if self.length == 0 {
+            panic!("No value to remove");
+        }
+        self.length -= 1;
+        let layout = D::layout::<T>(self.metadata);
+        unsafe {
+            let target = alloc_layout(layout);
+            (self.item(self.length) as *const u8).copy_to_nonoverlapping(target.as_ptr(), layout.size());
+            Box::from_raw(D::handle_ptr(target.as_ptr(), self.metadata))
+        }
+    }
+
+    /// Removes the item at the index as if it was immediately dropped,
+    /// shifting all items after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index has no item.
+    pub fn remove(&mut self, ix: usize) {
+        if ix >= self.length {
+            panic!("Out of bounds");
+        }
+        let old_len = self.length;
+        // Pre-poop the pants
+        self.length = ix;
+        unsafe {
+            drop_in_place(self.item(ix));
+            (self.item(ix + 1) as *const u8)
+                .copy_to(self.item(ix) as *mut u8, (old_len - ix - 1) * self.item_size());
+        }
+        // Clean the pants
+        self.length = old_len - 1;
+    }
+
+    /// Returns the item at the index, if it exists.
+    #[inline(always)]
+    pub fn get(&self, ix: usize) -> Option<&Handle<T, D>> {
+        if ix < self.length {
+            Some(unsafe { &*self.item(ix) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at the index mutably, if it exists.
+    #[inline(always)]
+    pub fn get_mut(&mut self, ix: usize) -> Option<&mut Handle<T, D>> {
+        if ix < self.length {
+            Some(unsafe { &mut *self.item(ix) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the item at the index, without checking that it exists.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked(&self, ix: usize) -> &Handle<T, D> {
+        &*self.item(ix)
+    }
+
+    /// Returns the item at the index mutably, without checking that it
+    /// exists.
+    ///
+    /// # Safety
+    ///
+    /// The index must be less than the number of items.
+    #[inline(always)]
+    pub unsafe fn get_unchecked_mut(&mut self, ix: usize) -> &mut Handle<T, D> {
+        &mut *self.item(ix)
+    }
+
+    /// Swaps the two items in place, moving the entire value and tail of
+    /// each.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no item at either index.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.length || b >= self.length {
+            index_out_of_bounds(a.max(b), self.length, self.metadata);
+        }
+        if a == b {
+            return;
+        }
+        unsafe { swap_nonoverlapping(
+            self.item(a) as *mut u8,
+            self.item(b) as *mut u8,
+            self.item_size(),
+        ) }
+    }
+
+    /// Returns an iterator over the items.
+    #[inline(always)]
+    pub fn iter(&self) -> Iter<'_, T, D> {
+        self.into_iter()
+    }
+
+    /// Returns an iterator over the items, allowing modification.
+    #[inline(always)]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, D> {
+        self.into_iter()
+    }
+}
+
+impl<T, D: ?Sized + CloneTail> Vec<T, D> {
+    /// Adds an item, populating the tail in place by cloning the one
+    /// provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tail has different metadata. If cloning the tail panics,
+    /// the value is leaked.
+    pub fn push(&mut self, value: T, tail: &D) {
+        self.check_tail(tail);
+        self.reserve(1);
+        unsafe {
+            let handle = self.item(self.length);
+            write(Handle::value_ptr(handle), value);
+            tail.clone_to(Handle::tail_ptr(handle));
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Inserts an item, populating the tail in place by cloning the one
+    /// provided.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tail has different metadata. Panics if any index lower
+    /// than the one provided has no item. If cloning the tail panics, the
+    /// value is leaked.
+    pub fn insert(&mut self, ix: usize, value: T, tail: &D) {
+        self.check_insert(ix);
+        self.check_tail(tail);
+        self.reserve(1);
+        unsafe {
+            let handle = self.item(self.length);
+            write(Handle::value_ptr(handle), value);
+            tail.clone_to(Handle::tail_ptr(handle));
+            self.shift_in(ix);
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+}
+
+impl<T, D: ?Sized + Dst> Vec<T, D> {
+    /// Adds an item, populating the tail in place from its fields and the
+    /// cells taken from the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator has insufficient element count, in which case
+    /// the value, the fields and any taken cells are leaked.
+    pub fn push_fields(&mut self, value: T, fields: D::Fields, cells: impl IntoIterator<Item=D::Cell>) {
+        self.reserve(1);
+        unsafe {
+            let handle = self.item(self.length);
+            write(Handle::value_ptr(handle), value);
+            D::init(Handle::tail_ptr(handle), self.metadata, fields, &mut cells.into_iter());
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+
+    /// Inserts an item, populating the tail in place from its fields and the
+    /// cells taken from the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index lower than the one provided has no item. Panics if
+    /// the iterator has insufficient element count, in which case the value,
+    /// the fields and any taken cells are leaked.
+    pub fn insert_fields(
+        &mut self,
+        ix: usize,
+        value: T,
+        fields: D::Fields,
+        cells: impl IntoIterator<Item=D::Cell>,
+    ) {
+        self.check_insert(ix);
+        self.reserve(1);
+        unsafe {
+            let handle = self.item(self.length);
+            write(Handle::value_ptr(handle), value);
+            D::init(Handle::tail_ptr(handle), self.metadata, fields, &mut cells.into_iter());
+            self.shift_in(ix);
+        }
+
+        // This puts it in the drop
+        self.length += 1;
+    }
+}
@@ -0,0 +1,238 @@
+use std::{
+    marker::PhantomData,
+    ops::Range,
+};
+use crate::{
+    alloc::Alloc,
+    DstTail,
+    Handle,
+};
+
+mod traits;
+mod implementation;
+
+/// A vec of [`Handle`]s generic over the kind of tail, which may be a slice,
+/// a [`str`], or any type implementing [`DstTail`]. All tails share the same
+/// [`Metadata`](DstTail::Metadata), such as the length of a slice or string,
+/// which is provided when the [`Vec`] is created.
+///
+/// This has the basic item operations of a [`FixedVec`](crate::FixedVec),
+/// but none of the operations on cells, such as columns, views, or flat
+/// slices, which need the tail to be a slice.
+///
+/// # Usage
+///
+/// ```rust
+/// use dst::TailVec;
+///
+/// let mut vec = TailVec::<u32, str>::new(5);
+/// vec.push(1, "hello");
+/// vec.push(2, "world");
+/// assert_eq!(&vec[1].tail, "world");
+/// assert_eq!(vec.iter().map(|item| item.value).sum::<u32>(), 3);
+/// ```
+pub struct Vec<T, D: ?Sized + DstTail> {
+    ptr: Alloc,
+    length: usize,
+    metadata: D::Metadata,
+    _phantom: PhantomData<Box<Handle<T, D>>>,
+}
+
+/// Iterator over references of the items of a [`Vec`].
+pub struct Iter<'a, T, D: ?Sized + DstTail> {
+    iter: Range<usize>,
+    ptr: &'a Vec<T, D>,
+}
+
+/// Iterator over mutable references of the items of a [`Vec`].
+pub struct IterMut<'a, T, D: ?Sized + DstTail> {
+    iter: Range<usize>,
+    ptr: &'a mut Vec<T, D>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        ptr::slice_from_raw_parts_mut,
+        rc::Rc,
+    };
+    use crate::TailVec;
+    use super::*;
+
+    /// A tail defined outside of the crate, as a user would.
+    #[repr(transparent)]
+    struct Bits([u8]);
+
+    unsafe impl DstTail for Bits {
+        type Metadata = usize;
+
+        fn metadata(&self) -> usize {
+            self.0.len() * 8
+        }
+
+        fn handle_ptr<V>(ptr: *mut u8, metadata: usize) -> *mut Handle<V, Self> {
+            slice_from_raw_parts_mut(ptr, metadata.div_ceil(8)) as *mut Handle<V, Self>
+        }
+    }
+
+    impl Bits {
+        fn get(&self, bit: usize) -> bool {
+            self.0[bit / 8] & (1 << (bit % 8)) != 0
+        }
+    }
+
+    #[test]
+    fn strings() {
+        let mut vec = TailVec::<u8, str>::new(3);
+        for (ix, text) in ["abc", "def", "ghi"].iter().enumerate() {
+            vec.push(ix as u8, text);
+        }
+        vec.remove(1);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(&vec[1].tail, "ghi");
+        vec[0].tail.make_ascii_uppercase();
+        let boxed = vec.pop_boxed();
+        assert_eq!((boxed.value, &boxed.tail), (2, "ghi"));
+        vec.push_boxed(boxed);
+        assert_eq!(format!("{:?}", vec), r#"[Handle { value: 0, tail: "ABC" }, Handle { value: 2, tail: "ghi" }]"#);
+        assert_eq!(vec.clone(), vec);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatched tail")]
+    fn mismatched() {
+        TailVec::<(), str>::new(3).push((), "four");
+    }
+
+    #[test]
+    fn slices() {
+        let counter = Rc::new(());
+        let mut vec = TailVec::<Rc<()>, [Rc<()>]>::new(2);
+        vec.push_boxed(Handle::new_boxed(2, counter.clone(), vec![counter.clone(); 2]));
+        vec.push(counter.clone(), &[counter.clone(), counter.clone()]);
+        let copy = vec.clone();
+        assert_eq!(Rc::strong_count(&counter), 13);
+        vec.pop();
+        drop(copy);
+        assert_eq!(Rc::strong_count(&counter), 4);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn user_tail() {
+        let mut vec = TailVec::<u16, Bits>::new(16);
+        let mut boxed = Handle::<u16, [u8]>::new_boxed(2, 7, [0b101, 0b1]);
+        boxed.value = 9;
+        let boxed = unsafe { Box::from_raw(Box::into_raw(boxed) as *mut Handle<u16, Bits>) };
+        vec.push_boxed(boxed);
+        assert_eq!(vec[0].value, 9);
+        assert!(vec[0].tail.get(0));
+        assert!(!vec[0].tail.get(1));
+        assert!(vec[0].tail.get(8));
+        assert_eq!(vec.metadata(), 16);
+
+        unsafe {
+            vec.push_with(4, |tail| (tail as *mut u8).write_bytes(0xFF, 2));
+            vec.insert_with(0, 3, |tail| (tail as *mut u8).write_bytes(0, 2));
+        }
+        assert_eq!(vec.iter().map(|item| item.value).collect::<std::vec::Vec<_>>(), [3, 9, 4]);
+        assert!(!vec[0].tail.get(15));
+        assert!(vec[2].tail.get(15));
+    }
+
+    #[test]
+    fn insert_swap() {
+        let mut vec = TailVec::<u8, str>::new(2);
+        vec.push(0, "ab");
+        vec.insert(0, 1, "cd");
+        vec.insert(2, 2, "ef");
+        let mut other = TailVec::<u8, str>::new(2);
+        other.push(3, "gh");
+        vec.insert_boxed(1, other.pop_boxed());
+        assert_eq!(vec.iter().map(|item| &item.tail).collect::<std::vec::Vec<_>>(), ["cd", "gh", "ab", "ef"]);
+        vec.swap(0, 3);
+        vec.swap(1, 1);
+        assert_eq!(vec.iter().map(|item| item.value).collect::<std::vec::Vec<_>>(), [2, 3, 0, 1]);
+        assert_eq!(unsafe { &vec.get_unchecked(3).tail }, "cd");
+        unsafe { vec.get_unchecked_mut(0).tail.make_ascii_uppercase() };
+        assert_eq!(&vec[0].tail, "EF");
+    }
+
+    #[test]
+    #[should_panic(expected = "Out of bounds insert")]
+    fn insert_out_of_bounds() {
+        TailVec::<(), str>::new(1).insert(1, (), "a");
+    }
+
+    #[test]
+    fn insert_drops() {
+        let counter = Rc::new(());
+        let mut vec = TailVec::<Rc<()>, [Rc<()>]>::new(1);
+        for ix in 0..4 {
+            vec.insert(ix / 2, counter.clone(), std::slice::from_ref(&counter));
+        }
+        vec.swap(0, 3);
+        vec.remove(1);
+        assert_eq!(Rc::strong_count(&counter), 7);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derived() {
+        use crate::Dst;
+
+        #[derive(Dst)]
+        #[dst(clone)]
+        #[repr(C)]
+        struct Row {
+            id: u8,
+            name: Rc<str>,
+            cells: [u32],
+        }
+
+        #[derive(Dst)]
+        #[repr(C)]
+        struct Pair<T>(u16, [T]);
+
+        let name: Rc<str> = Rc::from("row");
+        let mut vec = TailVec::<(), Row>::new(3);
+        vec.push_fields((), (1, name.clone()), 10..);
+        vec.push_fields((), (2, name.clone()), vec![20; 3]);
+        let boxed = Row::new_boxed(3, (3, name.clone()), [7, 8, 9]);
+        assert_eq!(std::mem::size_of_val(&*boxed), Row::value_layout(3).size());
+        vec.push((), &boxed);
+        let copy = vec.clone();
+        assert_eq!(Rc::strong_count(&name), 8);
+        assert_eq!(copy[2].tail.cells, [7, 8, 9]);
+        assert_eq!(vec[0].tail.cells, [10, 11, 12]);
+        assert_eq!(vec.iter().map(|item| item.tail.id).sum::<u8>(), 6);
+        vec.insert_fields(1, (), (4, name.clone()), 40..);
+        vec.swap(0, 3);
+        assert_eq!(vec.iter().map(|item| item.tail.id).collect::<std::vec::Vec<_>>(), [3, 4, 2, 1]);
+        assert_eq!(vec[1].tail.cells, [40, 41, 42]);
+        drop((vec, copy, boxed));
+        assert_eq!(Rc::strong_count(&name), 1);
+
+        let pair = Pair::new_boxed(2, (5,), [String::from("a"), String::from("b")]);
+        assert_eq!((pair.0, &pair.1[1][..]), (5, "b"));
+        let mut vec = TailVec::<bool, Pair<String>>::new(2);
+        vec.push_fields(true, (6,), pair.1.iter().rev().cloned());
+        let popped = vec.pop_boxed();
+        assert!(popped.value);
+        assert_eq!((popped.tail.0, &popped.tail.1[0][..]), (6, "b"));
+    }
+
+    #[test]
+    fn zero_sized() {
+        let mut vec = TailVec::<(), [()]>::new(4);
+        assert_eq!(vec.capacity(), usize::MAX);
+        for _ in 0..10 {
+            vec.push((), &[(); 4]);
+        }
+        assert_eq!(vec.iter_mut().count(), 10);
+        assert_eq!(vec.pop_boxed().tail.len(), 4);
+    }
+}
@@ -0,0 +1,137 @@
+use std::ops::{
+    Index,
+    IndexMut,
+};
+use crate::{
+    alloc::dealloc_layout,
+    prelude::*,
+    util::index_out_of_bounds,
+    CloneTail,
+};
+use super::*;
+
+unsafe impl<T, D: ?Sized + DstTail> Send for Vec<T, D> where Handle<T, D>: Send {}
+unsafe impl<T, D: ?Sized + DstTail> Sync for Vec<T, D> where Handle<T, D>: Sync {}
+
+impl<T: PartialEq, D: ?Sized + DstTail + PartialEq> PartialEq for Vec<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.length == other.length
+            && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, D: ?Sized + DstTail + Eq> Eq for Vec<T, D> {}
+
+impl<T: Hash, D: ?Sized + DstTail + Hash> Hash for Vec<T, D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: Debug, D: ?Sized + DstTail + Debug> Debug for Vec<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f
+            .debug_list()
+            .entries(self.iter())
+            .finish()
+    }
+}
+
+impl<T: Clone, D: ?Sized + CloneTail> Clone for Vec<T, D> {
+    fn clone(&self) -> Self {
+        let mut new = Self::new(self.metadata);
+        new.reserve_exact(self.length);
+        for item in self {
+            new.push(item.value.clone(), &item.tail);
+        }
+        new
+    }
+}
+
+impl<T, D: ?Sized + DstTail> Index<usize> for Vec<T, D> {
+    type Output = Handle<T, D>;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(item) => item,
+            None => index_out_of_bounds(index, self.length, self.metadata),
+        }
+    }
+}
+
+impl<T, D: ?Sized + DstTail> IndexMut<usize> for Vec<T, D> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let (length, metadata) = (self.length, self.metadata);
+        match self.get_mut(index) {
+            Some(item) => item,
+            None => index_out_of_bounds(index, length, metadata),
+        }
+    }
+}
+
+impl<'a, T, D: ?Sized + DstTail> Iterator for Iter<'a, T, D> {
+    type Item = &'a Handle<T, D>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        let ptr = self.ptr;
+        self
+            .iter
+            .next()
+            .and_then(|ix| ptr.get(ix))
+    }
+}
+
+impl<'a, T, D: ?Sized + DstTail> Iterator for IterMut<'a, T, D> {
+    type Item = &'a mut Handle<T, D>;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self
+            .iter
+            .next()
+            // Cheating the lifetime
+            .and_then(|ix| self.ptr.get_mut(ix).map(|item| unsafe { &mut *(item as *mut _) }))
+    }
+}
+
+impl<'a, T, D: ?Sized + DstTail> IntoIterator for &'a Vec<T, D> {
+    type Item = &'a Handle<T, D>;
+    type IntoIter = Iter<'a, T, D>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            iter: 0..(self.length),
+            ptr: self,
+        }
+    }
+}
+
+impl<'a, T, D: ?Sized + DstTail> IntoIterator for &'a mut Vec<T, D> {
+    type Item = &'a mut Handle<T, D>;
+    type IntoIter = IterMut<'a, T, D>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut {
+            iter: 0..(self.length),
+            ptr: self,
+        }
+    }
+}
+
+impl<T, D: ?Sized + DstTail> Drop for Vec<T, D> {
+    fn drop(&mut self) {
+        while !self.is_empty() {
+            self.pop()
+        }
+        if let Some((ptr, capacity)) = self.ptr.take() {
+            unsafe { dealloc_layout(ptr, self.layout(capacity)) }
+        }
+    }
+}